@@ -0,0 +1,42 @@
+//! Combine CIDRs to supernetworks in fixed-capacity arrays, without heap allocation.
+
+mod v4;
+mod v6;
+
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+pub use v4::*;
+pub use v6::*;
+
+/// The error returned by `try_push` when the combined CIDRs do not fit in the capacity of an array combiner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    cidr: T,
+}
+
+impl<T> CapacityError<T> {
+    #[inline]
+    pub(crate) const fn new(cidr: T) -> Self {
+        CapacityError {
+            cidr,
+        }
+    }
+
+    /// Get the CIDR which could not be pushed.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.cidr
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the capacity of the combiner is exhausted")
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
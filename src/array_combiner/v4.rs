@@ -0,0 +1,125 @@
+use core::{
+    fmt::{self, Debug, Display, Formatter, Write},
+    net::Ipv4Addr,
+    ops::Deref,
+};
+
+use cidr::Ipv4Cidr;
+
+use super::CapacityError;
+use crate::{
+    merge::{place, Placement},
+    Ipv4CidrSize,
+};
+
+/// To combine multiple IPv4 CIDRs to supernetworks, storing at most `N` CIDRs inline.
+#[derive(Clone, Copy)]
+pub struct ArrayIpv4CidrCombiner<const N: usize> {
+    cidrs:  [Ipv4Cidr; N],
+    length: usize,
+}
+
+impl<const N: usize> Default for ArrayIpv4CidrCombiner<N> {
+    #[inline]
+    fn default() -> Self {
+        ArrayIpv4CidrCombiner::new()
+    }
+}
+
+impl<const N: usize> Deref for ArrayIpv4CidrCombiner<N> {
+    type Target = [Ipv4Cidr];
+
+    #[inline]
+    fn deref(&self) -> &[Ipv4Cidr] {
+        &self.cidrs[..self.length]
+    }
+}
+
+impl<const N: usize> Debug for ArrayIpv4CidrCombiner<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize> Display for ArrayIpv4CidrCombiner<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+
+        for (i, cidr) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_fmt(format_args!("{}/{}", cidr.first_address(), cidr.network_length()))?;
+        }
+
+        f.write_char(']')
+    }
+}
+
+impl<const N: usize> ArrayIpv4CidrCombiner<N> {
+    /// Create a new `ArrayIpv4CidrCombiner` instance.
+    #[inline]
+    pub fn new() -> ArrayIpv4CidrCombiner<N> {
+        ArrayIpv4CidrCombiner {
+            cidrs: [Ipv4Cidr::new_host(Ipv4Addr::UNSPECIFIED); N], length: 0
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[Ipv4Cidr] {
+        self
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+}
+
+impl<const N: usize> ArrayIpv4CidrCombiner<N> {
+    /// Push a CIDR into this combiner. Returns an error and leaves this combiner unchanged if the CIDRs cannot fit in the capacity even after merging.
+    pub fn try_push(&mut self, cidr: Ipv4Cidr) -> Result<(), CapacityError<Ipv4Cidr>> {
+        let (start, end, merged) = match place(self, cidr, |_| true) {
+            Placement::Covered(_) => return Ok(()),
+            Placement::Replace {
+                start,
+                end,
+                cidr,
+            } => (start, end, cidr),
+        };
+
+        let length = self.length - (end - start) + 1;
+
+        if length > N {
+            return Err(CapacityError::new(cidr));
+        }
+
+        self.cidrs.copy_within(end..self.length, start + 1);
+        self.cidrs[start] = merged;
+        self.length = length;
+
+        Ok(())
+    }
+
+    /// Check an IPv4 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv4: &Ipv4Addr) -> bool {
+        let index = self.partition_point(|cidr| cidr.first_address() <= *ipv4);
+
+        index > 0 && self[index - 1].contains(ipv4)
+    }
+
+    /// Get the total size of CIDRs.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.iter().map(|cidr| cidr.size()).sum()
+    }
+}
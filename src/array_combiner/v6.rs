@@ -0,0 +1,128 @@
+use core::{
+    fmt::{self, Debug, Display, Formatter, Write},
+    net::Ipv6Addr,
+    ops::Deref,
+};
+
+use cidr::Ipv6Cidr;
+
+use super::CapacityError;
+use crate::{
+    merge::{place, Placement},
+    Ipv6CidrSizeU128,
+};
+
+/// To combine multiple IPv6 CIDRs to supernetworks, storing at most `N` CIDRs inline.
+#[derive(Clone, Copy)]
+pub struct ArrayIpv6CidrCombiner<const N: usize> {
+    cidrs:  [Ipv6Cidr; N],
+    length: usize,
+}
+
+impl<const N: usize> Default for ArrayIpv6CidrCombiner<N> {
+    #[inline]
+    fn default() -> Self {
+        ArrayIpv6CidrCombiner::new()
+    }
+}
+
+impl<const N: usize> Deref for ArrayIpv6CidrCombiner<N> {
+    type Target = [Ipv6Cidr];
+
+    #[inline]
+    fn deref(&self) -> &[Ipv6Cidr] {
+        &self.cidrs[..self.length]
+    }
+}
+
+impl<const N: usize> Debug for ArrayIpv6CidrCombiner<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize> Display for ArrayIpv6CidrCombiner<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+
+        for (i, cidr) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_fmt(format_args!("{}/{}", cidr.first_address(), cidr.network_length()))?;
+        }
+
+        f.write_char(']')
+    }
+}
+
+impl<const N: usize> ArrayIpv6CidrCombiner<N> {
+    /// Create a new `ArrayIpv6CidrCombiner` instance.
+    #[inline]
+    pub fn new() -> ArrayIpv6CidrCombiner<N> {
+        ArrayIpv6CidrCombiner {
+            cidrs: [Ipv6Cidr::new_host(Ipv6Addr::UNSPECIFIED); N], length: 0
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[Ipv6Cidr] {
+        self
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+}
+
+impl<const N: usize> ArrayIpv6CidrCombiner<N> {
+    /// Push a CIDR into this combiner. Returns an error and leaves this combiner unchanged if the CIDRs cannot fit in the capacity even after merging.
+    pub fn try_push(&mut self, cidr: Ipv6Cidr) -> Result<(), CapacityError<Ipv6Cidr>> {
+        let (start, end, merged) = match place(self, cidr, |_| true) {
+            Placement::Covered(_) => return Ok(()),
+            Placement::Replace {
+                start,
+                end,
+                cidr,
+            } => (start, end, cidr),
+        };
+
+        let length = self.length - (end - start) + 1;
+
+        if length > N {
+            return Err(CapacityError::new(cidr));
+        }
+
+        self.cidrs.copy_within(end..self.length, start + 1);
+        self.cidrs[start] = merged;
+        self.length = length;
+
+        Ok(())
+    }
+
+    /// Check an IPv6 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv6: &Ipv6Addr) -> bool {
+        let index = self.partition_point(|cidr| cidr.first_address() <= *ipv6);
+
+        index > 0 && self[index - 1].contains(ipv6)
+    }
+
+    /// Get the total size of CIDRs. Returns `u128::MAX` if the total size is `2^128`.
+    #[inline]
+    pub fn size_u128_saturating(&self) -> u128 {
+        self.iter().fold(0u128, |sum, cidr| match cidr.size_u128_checked() {
+            Some(size) => sum.saturating_add(size),
+            None => u128::MAX,
+        })
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    env, fmt, fs,
+    io::{self, BufWriter, Read, Write},
+    net::IpAddr,
+    process::ExitCode,
+    str::FromStr,
+};
+
+use cidr::{IpCidr, Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    iterator::{Ipv4CidrIpv4AddrIterator, Ipv6CidrIpv6AddrIterator},
+    separator::{Ipv4CidrSeparator, Ipv6CidrSeparator},
+    Ipv4CidrInfo, Ipv4CidrSize, Ipv6CidrInfo, Ipv6CidrSizeU128,
+};
+
+const USAGE: &str = "\
+Usage: cidr-utils <COMMAND> [ARGS]
+
+Commands:
+  aggregate [FILE]...             Combine the CIDRs of the files (or stdin) and print them
+  contains <CIDRS> <ADDRESS>...   Check whether the addresses are in the CIDRs
+  split <CIDR> --count <N>        Evenly divide a CIDR into N subnetworks
+  split <CIDR> --bits <LENGTH>    Divide a CIDR into subnetworks with a network length
+  expand <CIDR>...                Print every address of the CIDRs
+  info <CIDR>...                  Print the size, masks and host range of the CIDRs
+
+CIDRS is a comma-separated list of CIDRs, or @FILE to read them from a file (@- for stdin).
+Input files contain CIDRs separated by whitespace or commas. Text after `#` is ignored.
+
+Options:
+  -h, --help       Print this help
+  -V, --version    Print the version
+
+Exit status:
+  0    Success
+  1    An error occurred, or `contains` found an address which is not in the CIDRs
+  2    Invalid command line usage";
+
+/// An error which is reported to stderr, with its exit code. The code `0` means stdout has been closed, which exits quietly.
+struct Failure {
+    message: String,
+    code:    u8,
+}
+
+impl Failure {
+    #[inline]
+    fn usage(message: impl Into<String>) -> Self {
+        Failure {
+            message: message.into(), code: 2
+        }
+    }
+
+    #[inline]
+    fn runtime(message: impl Into<String>) -> Self {
+        Failure {
+            message: message.into(), code: 1
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        if error.kind() == io::ErrorKind::BrokenPipe {
+            // the reader has gone away (e.g. `| head`), which is not a failure
+            Failure {
+                message: String::new(), code: 0
+            }
+        } else {
+            Failure::runtime(error.to_string())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        None | Some("-h" | "--help" | "help") => print(format_args!("{USAGE}")),
+        Some("-V" | "--version") => print(format_args!("cidr-utils {}", env!("CARGO_PKG_VERSION"))),
+        Some("aggregate") => aggregate(&args[1..]),
+        Some("contains") => contains(&args[1..]),
+        Some("split") => split(&args[1..]),
+        Some("expand") => expand(&args[1..]),
+        Some("info") => info(&args[1..]),
+        Some(command) => Err(Failure::usage(format!("unknown command `{command}`"))),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(failure) if failure.code == 0 => ExitCode::SUCCESS,
+        Err(failure) => {
+            eprintln!("cidr-utils: {}", failure.message);
+
+            if failure.code == 2 {
+                eprintln!("Try `cidr-utils --help` for more information.");
+            }
+
+            ExitCode::from(failure.code)
+        },
+    }
+}
+
+#[inline]
+fn print(text: fmt::Arguments) -> Result<bool, Failure> {
+    writeln!(io::stdout().lock(), "{text}")?;
+
+    Ok(true)
+}
+
+#[inline]
+fn parse_cidr(s: &str) -> Result<IpCidr, Failure> {
+    IpCidr::from_str(s).map_err(|_| Failure::runtime(format!("invalid CIDR `{s}`")))
+}
+
+fn parse_cidrs(text: &str) -> Result<Vec<IpCidr>, Failure> {
+    let mut cidrs = Vec::new();
+
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap();
+
+        for token in line.split(|c: char| c.is_whitespace() || c == ',') {
+            if !token.is_empty() {
+                cidrs.push(parse_cidr(token)?);
+            }
+        }
+    }
+
+    Ok(cidrs)
+}
+
+fn read_source(path: &str) -> Result<String, Failure> {
+    if path == "-" {
+        let mut text = String::new();
+
+        io::stdin().read_to_string(&mut text)?;
+
+        Ok(text)
+    } else {
+        fs::read_to_string(path).map_err(|error| Failure::runtime(format!("{path}: {error}")))
+    }
+}
+
+fn combine(cidrs: Vec<IpCidr>) -> (Ipv4CidrCombiner, Ipv6CidrCombiner) {
+    let mut ipv4 = Ipv4CidrCombiner::new();
+    let mut ipv6 = Ipv6CidrCombiner::new();
+
+    for cidr in cidrs {
+        match cidr {
+            IpCidr::V4(cidr) => ipv4.push(cidr),
+            IpCidr::V6(cidr) => ipv6.push(cidr),
+        }
+    }
+
+    (ipv4, ipv6)
+}
+
+fn aggregate(args: &[String]) -> Result<bool, Failure> {
+    let mut cidrs = Vec::new();
+
+    if args.is_empty() {
+        cidrs.extend(parse_cidrs(&read_source("-")?)?);
+    } else {
+        for path in args {
+            cidrs.extend(parse_cidrs(&read_source(path)?)?);
+        }
+    }
+
+    let (ipv4, ipv6) = combine(cidrs);
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for cidr in ipv4.iter() {
+        writeln!(out, "{cidr}")?;
+    }
+
+    for cidr in ipv6.iter() {
+        writeln!(out, "{cidr}")?;
+    }
+
+    out.flush()?;
+
+    Ok(true)
+}
+
+fn contains(args: &[String]) -> Result<bool, Failure> {
+    let (cidrs, addresses) = match args.split_first() {
+        Some((cidrs, addresses)) if !addresses.is_empty() => (cidrs, addresses),
+        _ => return Err(Failure::usage("`contains` needs CIDRs and at least one address")),
+    };
+
+    let cidrs = match cidrs.strip_prefix('@') {
+        Some(path) => parse_cidrs(&read_source(path)?)?,
+        None => parse_cidrs(cidrs)?,
+    };
+
+    let (ipv4, ipv6) = combine(cidrs);
+
+    let mut all = true;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for address in addresses {
+        let contained = match IpAddr::from_str(address) {
+            Ok(IpAddr::V4(addr)) => ipv4.contains(&addr),
+            Ok(IpAddr::V6(addr)) => ipv6.contains(&addr),
+            Err(_) => return Err(Failure::runtime(format!("invalid address `{address}`"))),
+        };
+
+        all &= contained;
+
+        writeln!(out, "{address}\t{}", if contained { "yes" } else { "no" })?;
+    }
+
+    out.flush()?;
+
+    Ok(all)
+}
+
+fn split(args: &[String]) -> Result<bool, Failure> {
+    let (cidr, option, value) = match args {
+        [cidr, option, value] => (parse_cidr(cidr)?, option.as_str(), value.as_str()),
+        _ => {
+            return Err(Failure::usage(
+                "`split` needs a CIDR and either `--count <N>` or `--bits <LENGTH>`",
+            ))
+        },
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    match option {
+        "--count" => {
+            let n = usize::from_str(value)
+                .map_err(|_| Failure::usage(format!("invalid count `{value}`")))?;
+
+            let write_combiner =
+                |out: &mut BufWriter<_>, cidrs: Vec<String>| writeln!(out, "{}", cidrs.join(" "));
+
+            match cidr {
+                IpCidr::V4(cidr) => {
+                    let combiners = Ipv4CidrSeparator::divide_by(&cidr, n).ok_or_else(|| {
+                        Failure::runtime(format!("cannot divide {cidr} into {n} subnetworks"))
+                    })?;
+
+                    for combiner in combiners {
+                        write_combiner(
+                            &mut out,
+                            combiner.iter().map(Ipv4Cidr::to_string).collect(),
+                        )?;
+                    }
+                },
+                IpCidr::V6(cidr) => {
+                    let combiners = Ipv6CidrSeparator::divide_by(&cidr, n).ok_or_else(|| {
+                        Failure::runtime(format!("cannot divide {cidr} into {n} subnetworks"))
+                    })?;
+
+                    for combiner in combiners {
+                        write_combiner(
+                            &mut out,
+                            combiner.iter().map(Ipv6Cidr::to_string).collect(),
+                        )?;
+                    }
+                },
+            }
+        },
+        "--bits" => {
+            let bits = u8::from_str(value)
+                .map_err(|_| Failure::usage(format!("invalid network length `{value}`")))?;
+
+            let error =
+                || Failure::runtime(format!("cannot divide {cidr} into subnetworks of /{bits}"));
+
+            match cidr {
+                IpCidr::V4(cidr) => {
+                    for cidr in Ipv4CidrSeparator::sub_networks(&cidr, bits).ok_or_else(error)? {
+                        writeln!(out, "{cidr}")?;
+                    }
+                },
+                IpCidr::V6(cidr) => {
+                    for cidr in Ipv6CidrSeparator::sub_networks(&cidr, bits).ok_or_else(error)? {
+                        writeln!(out, "{cidr}")?;
+                    }
+                },
+            }
+        },
+        _ => return Err(Failure::usage(format!("unknown option `{option}`"))),
+    }
+
+    out.flush()?;
+
+    Ok(true)
+}
+
+fn expand(args: &[String]) -> Result<bool, Failure> {
+    if args.is_empty() {
+        return Err(Failure::usage("`expand` needs at least one CIDR"));
+    }
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for cidr in args {
+        match parse_cidr(cidr)? {
+            IpCidr::V4(cidr) => {
+                for addr in Ipv4CidrIpv4AddrIterator::new(&cidr) {
+                    writeln!(out, "{addr}")?;
+                }
+            },
+            IpCidr::V6(cidr) => {
+                for addr in Ipv6CidrIpv6AddrIterator::new(&cidr) {
+                    writeln!(out, "{addr}")?;
+                }
+            },
+        }
+    }
+
+    out.flush()?;
+
+    Ok(true)
+}
+
+fn info(args: &[String]) -> Result<bool, Failure> {
+    if args.is_empty() {
+        return Err(Failure::usage("`info` needs at least one CIDR"));
+    }
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for (i, cidr) in args.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+
+        match parse_cidr(cidr)? {
+            IpCidr::V4(cidr) => {
+                writeln!(out, "Network:    {}/{}", cidr.first_address(), cidr.network_length())?;
+                writeln!(out, "Netmask:    {}", cidr.netmask())?;
+                writeln!(out, "Wildcard:   {}", cidr.wildcard_mask())?;
+
+                match cidr.broadcast() {
+                    Some(broadcast) => writeln!(out, "Broadcast:  {broadcast}")?,
+                    None => writeln!(out, "Broadcast:  -")?,
+                }
+
+                writeln!(out, "First host: {}", cidr.first_host())?;
+                writeln!(out, "Last host:  {}", cidr.last_host())?;
+                writeln!(out, "Hosts:      {}", cidr.host_count())?;
+                writeln!(out, "Size:       {}", cidr.size())?;
+            },
+            IpCidr::V6(cidr) => {
+                writeln!(out, "Network:    {}/{}", cidr.first_address(), cidr.network_length())?;
+                writeln!(out, "Netmask:    {}", cidr.netmask())?;
+                writeln!(out, "Wildcard:   {}", cidr.wildcard_mask())?;
+                writeln!(out, "First host: {}", cidr.first_host())?;
+                writeln!(out, "Last host:  {}", cidr.last_host())?;
+
+                match cidr.size_u128_checked() {
+                    Some(size) => writeln!(out, "Size:       {size} (2^{})", cidr.log2_size())?,
+                    None => writeln!(out, "Size:       2^128")?,
+                }
+            },
+        }
+    }
+
+    out.flush()?;
+
+    Ok(true)
+}
@@ -1,5 +1,4 @@
-use core::fmt::{self, Display, Formatter};
-use std::fmt::Write;
+use core::fmt::{self, Display, Formatter, Write};
 
 use cidr::{Ipv4Cidr, Ipv6Cidr};
 
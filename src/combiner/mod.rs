@@ -1,6 +1,9 @@
 mod fmt;
+mod policy;
+mod trie;
 mod v4;
 mod v6;
 
+pub use policy::*;
 pub use v4::*;
 pub use v6::*;
@@ -0,0 +1,57 @@
+/// Rules about how a combiner merges sibling CIDRs into their supernetworks.
+///
+/// CIDRs which are contained by other CIDRs are always removed, whatever the policy is.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::combiner::{Ipv4CidrCombiner, MergePolicy};
+///
+/// let mut combiner = Ipv4CidrCombiner::with_policy(MergePolicy {
+///     min_network_length: 16,
+///     ..MergePolicy::new()
+/// });
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/16").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("10.1.0.0/16").unwrap());
+///
+/// assert_eq!(2, combiner.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MergePolicy {
+    /// Never merge siblings into a supernetwork whose network length is shorter than this value. The default value is `0`.
+    pub min_network_length: u8,
+    /// Never merge siblings into a supernetwork whose network length is longer than this value. The default value is `128`.
+    pub max_network_length: u8,
+    /// Whether to merge siblings at all. The default value is `true`.
+    pub merge_siblings:     bool,
+}
+
+impl MergePolicy {
+    /// Create a new `MergePolicy` instance which merges siblings without limits.
+    #[inline]
+    pub const fn new() -> MergePolicy {
+        MergePolicy {
+            min_network_length: 0, max_network_length: 128, merge_siblings: true
+        }
+    }
+
+    /// Check whether two siblings whose network length is `bits` can be merged.
+    #[inline]
+    pub(crate) const fn can_merge(&self, bits: u8) -> bool {
+        // the supernetwork is `bits - 1` long
+        self.merge_siblings
+            && bits > self.min_network_length
+            && bits <= self.max_network_length.saturating_add(1)
+    }
+}
+
+impl Default for MergePolicy {
+    #[inline]
+    fn default() -> Self {
+        MergePolicy::new()
+    }
+}
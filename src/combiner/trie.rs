@@ -0,0 +1,321 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use super::MergePolicy;
+
+/// A CIDR of a combiner (by its index), or the common supernetwork of the CIDRs at `index` and `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrieNode {
+    Cidr(usize),
+    Supernet(usize),
+}
+
+#[derive(Debug)]
+struct Node {
+    bits:       u8,
+    /// The network length of the block which is taken for this node. It is shorter than `bits` if the merge policy does not allow the node itself.
+    block_bits: u8,
+    parent:     Option<usize>,
+    children:   Option<(usize, usize)>,
+    /// The number of CIDRs under this node.
+    leaves:     usize,
+    /// The number of addresses in the block of this node which are not covered by the CIDRs.
+    extra:      u128,
+}
+
+/// The binary trie formed by the sorted CIDRs of a combiner and the common supernetworks of the adjacent ones, with the single-child paths compressed.
+///
+/// The nodes `0..n` are the CIDRs, and the node `n + i` is the common supernetwork of the CIDRs `i` and `i + 1`. Any CIDR which covers some of the CIDRs without partially overlapping the others is at least as wide as one of these nodes, so only the nodes need to be considered for aggregation.
+///
+/// A node longer than the maximum network length of the merge policy is not allowed, except that the topmost one of them is widened to its supernetwork of the maximum network length, which is what merging siblings up to the policy would produce.
+#[derive(Debug)]
+pub(crate) struct Trie {
+    nodes:  Vec<Node>,
+    root:   usize,
+    cidrs:  usize,
+    policy: MergePolicy,
+}
+
+impl Trie {
+    /// Build a trie from the network lengths of the sorted CIDRs (at least one) and of the common supernetworks of the adjacent ones.
+    pub(crate) fn new(
+        max_bits: u8,
+        cidr_bits: &[u8],
+        supernet_bits: &[u8],
+        policy: MergePolicy,
+    ) -> Trie {
+        let n = cidr_bits.len();
+
+        debug_assert!(n > 0 && supernet_bits.len() + 1 == n);
+
+        let mut nodes: Vec<Node> = cidr_bits
+            .iter()
+            .chain(supernet_bits.iter())
+            .map(|&bits| Node {
+                bits,
+                block_bits: bits,
+                parent: None,
+                children: None,
+                leaves: 1,
+                extra: 0,
+            })
+            .collect();
+
+        // the right spine, from the root to the last added CIDR
+        let mut spine = vec![0];
+
+        for (i, &bits) in supernet_bits.iter().enumerate() {
+            let node = n + i;
+
+            // the supernetworks of adjacent pairs never have the same network length on a spine
+            let mut child = spine.pop().unwrap();
+
+            while let Some(&top) = spine.last() {
+                if nodes[top].bits > bits {
+                    child = spine.pop().unwrap();
+                } else {
+                    break;
+                }
+            }
+
+            if let Some(&top) = spine.last() {
+                let (left, _) = nodes[top].children.unwrap();
+
+                nodes[top].children = Some((left, node));
+                nodes[node].parent = Some(top);
+            }
+
+            nodes[node].children = Some((child, i + 1));
+            nodes[child].parent = Some(node);
+            nodes[i + 1].parent = Some(node);
+
+            spine.push(node);
+            spine.push(i + 1);
+        }
+
+        let max_length = policy.max_network_length;
+
+        for node in n..nodes.len() {
+            if nodes[node].bits > max_length
+                && nodes[node].parent.map_or(true, |parent| nodes[parent].bits < max_length)
+            {
+                nodes[node].block_bits = max_length;
+            }
+        }
+
+        let mut trie = Trie {
+            nodes,
+            root: spine[0],
+            cidrs: n,
+            policy,
+        };
+
+        trie.fill(max_bits, trie.root);
+
+        trie
+    }
+
+    /// Count the CIDRs and the uncovered addresses under a node. The recursion is bounded by the number of bits.
+    fn fill(&mut self, max_bits: u8, node: usize) {
+        if let Some((left, right)) = self.nodes[node].children {
+            self.fill(max_bits, left);
+            self.fill(max_bits, right);
+
+            // the children lie in the two different halves of the node, so none of these overflow
+            let Node {
+                bits,
+                block_bits,
+                ..
+            } = self.nodes[node];
+
+            let half = 1u128 << (max_bits - 1 - bits);
+            let gap = |child: &Node| half - 1 - size_minus_one(max_bits, child.block_bits);
+
+            let (left, right) = (&self.nodes[left], &self.nodes[right]);
+
+            let leaves = left.leaves + right.leaves;
+            let extra = left.extra
+                + right.extra
+                + gap(left)
+                + gap(right)
+                + (size_minus_one(max_bits, block_bits) - size_minus_one(max_bits, bits));
+
+            self.nodes[node].leaves = leaves;
+            self.nodes[node].extra = extra;
+        }
+    }
+
+    #[inline]
+    fn is_mergeable(&self, node: usize) -> bool {
+        // merging siblings one bit longer than the block gives the block, and a node longer than the maximum network length is only allowed if it has been widened
+        node < self.cidrs || self.policy.can_merge(self.nodes[node].block_bits + 1)
+    }
+
+    #[inline]
+    fn ancestors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.nodes[node].parent, |&node| self.nodes[node].parent)
+    }
+
+    /// Get the fewest CIDRs that can be reached under the merge policy.
+    fn min_entries(&self, node: usize) -> usize {
+        match self.nodes[node].children {
+            Some(_) if self.is_mergeable(node) => 1,
+            Some((left, right)) => self.min_entries(left) + self.min_entries(right),
+            None => 1,
+        }
+    }
+
+    /// Greedily merge CIDRs into the nodes which cover the fewest extra addresses, until there are at most `max_entries` nodes. Returns the number of extra addresses and the chosen nodes in order.
+    ///
+    /// Merging a node whose parent has the same number of extra addresses lets the parent cover its sibling without any more extra addresses, so on a tie the node which absorbs more CIDRs is taken first, which is the parent.
+    pub(crate) fn aggregate(&self, max_entries: usize) -> Option<(u128, Vec<TrieNode>)> {
+        if self.min_entries(self.root) > max_entries {
+            return None;
+        }
+
+        let mut extra: Vec<u128> = self.nodes.iter().map(|node| node.extra).collect();
+        let mut leaves: Vec<usize> = self.nodes.iter().map(|node| node.leaves).collect();
+        let mut merged = vec![false; self.nodes.len()];
+
+        let mut heap = BinaryHeap::new();
+
+        for node in self.cidrs..self.nodes.len() {
+            if self.is_mergeable(node) {
+                heap.push((Reverse(extra[node]), leaves[node], Reverse(node)));
+            }
+        }
+
+        let mut entries = self.cidrs;
+        let mut total_extra = 0;
+
+        while entries > max_entries {
+            let (Reverse(node_extra), node_leaves, Reverse(node)) = heap.pop()?;
+
+            // skip the outdated keys, and the nodes already covered by a merged ancestor
+            if merged[node]
+                || node_extra != extra[node]
+                || node_leaves != leaves[node]
+                || self.ancestors(node).any(|ancestor| merged[ancestor])
+            {
+                continue;
+            }
+
+            merged[node] = true;
+            total_extra += node_extra;
+            entries -= node_leaves - 1;
+
+            // only the keys of the ancestors change
+            for ancestor in self.ancestors(node) {
+                extra[ancestor] -= node_extra;
+                leaves[ancestor] -= node_leaves - 1;
+
+                if self.is_mergeable(ancestor) {
+                    heap.push((Reverse(extra[ancestor]), leaves[ancestor], Reverse(ancestor)));
+                }
+            }
+        }
+
+        Some((total_extra, self.collect(|node| merged[node])))
+    }
+
+    /// Choose at most `max_entries` nodes which cover the fewest extra addresses, by dynamic programming over the trie in O(CIDRs × `max_entries`) time. Returns the number of extra addresses and the chosen nodes in order.
+    pub(crate) fn cover(&self, max_entries: usize) -> Option<(u128, Vec<TrieNode>)> {
+        let mut splits = vec![Vec::new(); self.nodes.len()];
+
+        let costs = self.cover_node(self.root, max_entries, &mut splits);
+
+        // the fewest nodes among the ones with the fewest extra addresses
+        let (count, &extra) = costs.iter().enumerate().min_by_key(|&(_, &cost)| cost)?;
+
+        if extra == u128::MAX {
+            return None;
+        }
+
+        let mut chosen = vec![false; self.nodes.len()];
+
+        self.choose(self.root, count, &splits, &mut chosen);
+
+        Some((extra, self.collect(|node| chosen[node])))
+    }
+
+    /// Get the fewest extra addresses to cover the CIDRs under a node with exactly `count` nodes, indexed by `count`. `u128::MAX` means impossible. `splits[node][count]` records how many of the nodes go to the left child, or `0` if the node itself is taken.
+    fn cover_node(&self, node: usize, max_entries: usize, splits: &mut [Vec<usize>]) -> Vec<u128> {
+        let max_count = self.nodes[node].leaves.min(max_entries);
+
+        let mut costs = vec![u128::MAX; max_count + 1];
+
+        match self.nodes[node].children {
+            Some((left, right)) => {
+                let left_costs = self.cover_node(left, max_entries, splits);
+                let right_costs = self.cover_node(right, max_entries, splits);
+
+                let mut split = vec![0; max_count + 1];
+
+                for (a, &left_cost) in left_costs.iter().enumerate().skip(1) {
+                    for (b, &right_cost) in
+                        right_costs.iter().enumerate().skip(1).take(max_count.saturating_sub(a))
+                    {
+                        let cost = left_cost.saturating_add(right_cost);
+
+                        if cost < costs[a + b] {
+                            costs[a + b] = cost;
+                            split[a + b] = a;
+                        }
+                    }
+                }
+
+                if max_count > 0 && self.is_mergeable(node) {
+                    costs[1] = self.nodes[node].extra;
+                }
+
+                splits[node] = split;
+            },
+            None => {
+                if max_count > 0 {
+                    costs[1] = 0;
+                }
+            },
+        }
+
+        costs
+    }
+
+    /// Mark the nodes chosen by `cover_node`.
+    fn choose(&self, node: usize, count: usize, splits: &[Vec<usize>], chosen: &mut [bool]) {
+        if let Some((left, right)) = self.nodes[node].children {
+            match splits[node][count] {
+                0 => chosen[node] = true,
+                split => {
+                    self.choose(left, split, splits, chosen);
+                    self.choose(right, count - split, splits, chosen);
+                },
+            }
+        }
+    }
+
+    /// Get the topmost chosen nodes in order, with the CIDRs not under any of them.
+    fn collect(&self, chosen: impl Fn(usize) -> bool) -> Vec<TrieNode> {
+        let mut output: Vec<TrieNode> = Vec::new();
+
+        for cidr in 0..self.cidrs {
+            let node = self.ancestors(cidr).filter(|&node| chosen(node)).last();
+
+            let node = match node {
+                Some(node) => TrieNode::Supernet(node - self.cidrs),
+                None => TrieNode::Cidr(cidr),
+            };
+
+            if output.last() != Some(&node) {
+                output.push(node);
+            }
+        }
+
+        output
+    }
+}
+
+/// Get the size of a block minus one, which does not overflow for `/0`.
+#[inline]
+fn size_minus_one(max_bits: u8, bits: u8) -> u128 {
+    u128::MAX.checked_shr(128 - (max_bits - bits) as u32).unwrap_or(0)
+}
@@ -1,13 +1,32 @@
-use core::ops::Deref;
-use std::net::Ipv4Addr;
+use alloc::vec::Vec;
+use core::{net::Ipv4Addr, ops::Deref};
 
 use cidr::Ipv4Cidr;
-
-use crate::Ipv4CidrSize;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use super::{
+    trie::{Trie, TrieNode},
+    MergePolicy,
+};
+#[cfg(feature = "diff")]
+use crate::diff::Ipv4CidrDiff;
+#[cfg(feature = "rayon")]
+use crate::iterator::Ipv4CidrIpv4AddrIterator;
+#[cfg(feature = "registry")]
+use crate::registry::Ipv4SpecialPurposeRegistry;
+#[cfg(feature = "sampler")]
+use crate::sampler::Ipv4CidrSampler;
+#[cfg(feature = "utilization")]
+use crate::utilization::Ipv4Utilization;
+use crate::{
+    merge::{place, Placement},
+    Ipv4CidrNavigation, Ipv4CidrSize,
+};
 
 /// To combine multiple IPv4 CIDRs to supernetworks.
 #[derive(Debug, Clone)]
-pub struct Ipv4CidrCombiner(Vec<Ipv4Cidr>);
+pub struct Ipv4CidrCombiner(Vec<Ipv4Cidr>, MergePolicy);
 
 impl Default for Ipv4CidrCombiner {
     #[inline]
@@ -29,13 +48,19 @@ impl Ipv4CidrCombiner {
     /// Create a new `Ipv4CidrCombiner` instance.
     #[inline]
     pub const fn new() -> Ipv4CidrCombiner {
-        Ipv4CidrCombiner(Vec::new())
+        Ipv4CidrCombiner(Vec::new(), MergePolicy::new())
+    }
+
+    /// Create a new `Ipv4CidrCombiner` instance with a specific merge policy.
+    #[inline]
+    pub const fn with_policy(policy: MergePolicy) -> Ipv4CidrCombiner {
+        Ipv4CidrCombiner(Vec::new(), policy)
     }
 
     /// Create a new `Ipv4CidrCombiner` instance with a specific capacity.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Ipv4CidrCombiner {
-        Ipv4CidrCombiner(Vec::with_capacity(capacity))
+        Ipv4CidrCombiner(Vec::with_capacity(capacity), MergePolicy::new())
     }
 
     /// Create a new `Ipv4CidrCombiner` instance with an existing array.
@@ -45,127 +70,235 @@ impl Ipv4CidrCombiner {
     /// You must ensure that the input array is ordered.
     #[inline]
     pub const unsafe fn from_ipv4_cidr_vec_unchecked(cidr_vec: Vec<Ipv4Cidr>) -> Ipv4CidrCombiner {
-        Ipv4CidrCombiner(cidr_vec)
+        Ipv4CidrCombiner(cidr_vec, MergePolicy::new())
     }
 
     #[inline]
     pub fn into_ipv4_cidr_vec(self) -> Vec<Ipv4Cidr> {
         self.0
     }
+
+    /// Get the merge policy of this combiner.
+    #[inline]
+    pub const fn policy(&self) -> MergePolicy {
+        self.1
+    }
 }
 
 impl Ipv4CidrCombiner {
     /// Push a CIDR into this combiner.
-    pub fn push(&mut self, mut cidr: Ipv4Cidr) {
-        if let Err(mut index) = self.0.binary_search(&cidr) {
-            if self.0.is_empty() {
-                self.0.push(cidr);
-            } else {
-                let pushable = if index == 0 {
-                    true
-                } else {
-                    let previous_cidr = self.0.get(index - 1).unwrap();
+    pub fn push(&mut self, cidr: Ipv4Cidr) {
+        if let Placement::Replace {
+            start,
+            end,
+            cidr,
+        } = place(&self.0, cidr, |bits| self.1.can_merge(bits))
+        {
+            self.0.splice(start..end, [cidr]);
+        }
+    }
 
-                    !previous_cidr.contains(&cidr.first_address())
-                };
+    /// Check an IPv4 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv4: &Ipv4Addr) -> bool {
+        for cidr in self.0.iter() {
+            if cidr.contains(ipv4) {
+                return true;
+            }
+        }
+
+        false
+    }
 
-                if pushable {
-                    loop {
-                        if index == self.0.len() {
-                            break;
-                        }
+    /// Get the total size of CIDRs.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        let mut sum = 0;
 
-                        let next = self.0.get(index).unwrap();
+        for cidr in self.0.iter() {
+            sum += cidr.size();
+        }
 
-                        if cidr.contains(&next.first_address()) {
-                            self.0.remove(index);
-                        } else {
-                            break;
-                        }
-                    }
+        sum
+    }
 
-                    let mut merging = true;
+    /// Remove the addresses of a CIDR from this combiner. Returns the removed parts.
+    pub fn subtract(&mut self, cidr: &Ipv4Cidr) -> Ipv4CidrCombiner {
+        let mut removed = Ipv4CidrCombiner::new();
 
-                    while merging {
-                        merging = false;
+        let first = cidr.first_address();
+        let last = cidr.last_address();
 
-                        if index < self.0.len() {
-                            let next_cidr = self.0.get(index).unwrap();
+        // the CIDRs overlapping `cidr` are contiguous
+        let start = self.0.partition_point(|c| c.last_address() < first);
+        let end = start + self.0[start..].partition_point(|c| c.first_address() <= last);
 
-                            let next_bits = next_cidr.network_length();
-                            let bits = cidr.network_length();
+        if start == end {
+            return removed;
+        }
 
-                            if bits > 0 && bits == next_bits {
-                                let next_prefix: u32 = next_cidr.first_address().into();
-                                let prefix: u32 = cidr.first_address().into();
+        let mut remaining = Vec::new();
 
-                                let d = next_prefix ^ prefix;
+        for entry in self.0[start..end].iter() {
+            if entry.network_length() >= cidr.network_length() {
+                removed.push(*entry);
+            } else {
+                // `entry` contains `cidr`, keep the siblings along the path from `entry` down to `cidr`
+                removed.push(*cidr);
 
-                                if d == 1 << (32 - bits) as u32 {
-                                    cidr = Ipv4Cidr::new(prefix.into(), bits - 1).unwrap();
+                for bits in entry.network_length() + 1..=cidr.network_length() {
+                    remaining.push(cidr.supernet(bits).unwrap().sibling().unwrap());
+                }
+            }
+        }
 
-                                    self.0.remove(index);
+        remaining.sort_unstable();
 
-                                    merging = true;
-                                }
-                            }
-                        }
+        self.0.splice(start..end, remaining);
 
-                        if index > 0 {
-                            let index_dec = index - 1;
+        removed
+    }
 
-                            let previous_cidr = self.0.get_mut(index_dec).unwrap();
+    /// Get the addresses of a CIDR which are not in these CIDRs, as aligned CIDRs.
+    pub fn free_blocks(&self, within: &Ipv4Cidr) -> Ipv4CidrCombiner {
+        let mut free = Ipv4CidrCombiner::new();
 
-                            let previous_bits = previous_cidr.network_length();
-                            let bits = cidr.network_length();
+        free.push(*within);
 
-                            if bits > 0 && bits == previous_bits {
-                                let previous_prefix: u32 = previous_cidr.first_address().into();
-                                let prefix: u32 = cidr.first_address().into();
+        let first = within.first_address();
+        let last = within.last_address();
 
-                                let d = prefix ^ previous_prefix;
+        let start = self.0.partition_point(|c| c.last_address() < first);
 
-                                if d == 1 << (32 - bits) as u32 {
-                                    self.0.remove(index_dec);
+        for cidr in self.0[start..].iter().take_while(|c| c.first_address() <= last) {
+            free.subtract(cidr);
+        }
 
-                                    index = index_dec;
+        free
+    }
 
-                                    cidr = Ipv4Cidr::new(previous_prefix.into(), previous_bits - 1)
-                                        .unwrap();
+    /// Find the lowest free block with a specific network length in a CIDR. Returns `None` if there is no such block.
+    pub fn first_free(&self, within: &Ipv4Cidr, network_length: u8) -> Option<Ipv4Cidr> {
+        if network_length < within.network_length() || network_length > 32 {
+            return None;
+        }
 
-                                    merging = true;
-                                }
-                            }
-                        }
-                    }
+        // free blocks are maximal and aligned, so a free block of this length lies at the start of one of them
+        self.free_blocks(within)
+            .iter()
+            .find(|cidr| cidr.network_length() <= network_length)
+            .map(|cidr| Ipv4Cidr::new(cidr.first_address(), network_length).unwrap())
+    }
 
-                    self.0.insert(index, cidr);
-                }
+    /// Iterate all addresses of these CIDRs in parallel.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_addrs(&self) -> impl ParallelIterator<Item = Ipv4Addr> + '_ {
+        self.0.par_iter().flat_map(Ipv4CidrIpv4AddrIterator::new)
+    }
+
+    /// Pick `n` distinct addresses uniformly at random from these CIDRs, in ascending order. The same seed always picks the same addresses. Returns `None` if there are fewer than `n` addresses.
+    #[cfg(feature = "sampler")]
+    #[inline]
+    pub fn sample(&self, n: usize, seed: u64) -> Option<Vec<Ipv4Addr>> {
+        Ipv4CidrSampler::sample_combiner(self, n, seed)
+    }
+
+    /// Report how much of a parent CIDR is used by these CIDRs.
+    #[cfg(feature = "utilization")]
+    #[inline]
+    pub fn utilization(&self, parent: &Ipv4Cidr) -> Ipv4Utilization {
+        Ipv4Utilization::new(self, parent)
+    }
+
+    /// Remove the addresses which are not globally reachable according to the IANA special-purpose address registry, such as private-use, loopback and documentation addresses. Returns the removed parts.
+    #[cfg(feature = "registry")]
+    pub fn retain_global(&mut self) -> Ipv4CidrCombiner {
+        let mut removed = Ipv4CidrCombiner::new();
+
+        for cidr in Ipv4SpecialPurposeRegistry::non_global().iter() {
+            for cidr in self.subtract(cidr).iter() {
+                removed.push(*cidr);
             }
         }
+
+        removed
     }
 
-    /// Check an IPv4 whether it is in these CIDRs.
+    /// Compare these CIDRs (as the old ones) with another combiner (as the new ones).
+    #[cfg(feature = "diff")]
     #[inline]
-    pub fn contains(&self, ipv4: &Ipv4Addr) -> bool {
-        for cidr in self.0.iter() {
-            if cidr.contains(ipv4) {
-                return true;
-            }
+    pub fn diff(&self, new: &Ipv4CidrCombiner) -> Ipv4CidrDiff {
+        Ipv4CidrDiff::new(self, new)
+    }
+
+    /// Merge CIDRs into covering supernetworks until there are at most `max_entries` CIDRs. Every merge picks the supernetwork which covers the fewest addresses outside these CIDRs. Merging stops as soon as `max_entries` is reached.
+    ///
+    /// The merge policy is respected. Nothing is merged if `merge_siblings` is `false`, no supernetwork is shorter than `min_network_length`, and a supernetwork longer than `max_network_length` is widened to that length.
+    ///
+    /// This is greedy, so more extra addresses than necessary may be covered. Use `covering_cidrs` to get the minimum.
+    ///
+    /// Returns the number of extra addresses which are covered, or `None` (without changing anything) if `max_entries` cannot be reached.
+    pub fn aggregate_to(&mut self, max_entries: usize) -> Option<u64> {
+        if self.0.len() <= max_entries {
+            return Some(0);
         }
 
-        false
+        let (extra, nodes) = self.trie().aggregate(max_entries)?;
+
+        *self = self.combine_trie_nodes(&nodes);
+
+        Some(extra as u64)
     }
 
-    /// Get the total size of CIDRs.
+    /// Build the trie of these CIDRs, which must not be empty.
+    fn trie(&self) -> Trie {
+        let cidr_bits: Vec<u8> = self.0.iter().map(|cidr| cidr.network_length()).collect();
+        let supernet_bits: Vec<u8> = self
+            .0
+            .windows(2)
+            .map(|pair| pair[0].common_supernet(&pair[1]).network_length())
+            .collect();
+
+        Trie::new(32, &cidr_bits, &supernet_bits, self.1)
+    }
+
+    /// Create a combiner with the same policy from the chosen nodes of the trie of these CIDRs.
+    fn combine_trie_nodes(&self, nodes: &[TrieNode]) -> Ipv4CidrCombiner {
+        let mut combiner = Ipv4CidrCombiner::with_policy(self.1);
+
+        for node in nodes {
+            let cidr = match *node {
+                TrieNode::Cidr(index) => self.0[index],
+                TrieNode::Supernet(index) => {
+                    let cidr = self.0[index].common_supernet(&self.0[index + 1]);
+
+                    // a supernetwork longer than the merge policy allows is taken as its widened block
+                    cidr.supernet(self.1.max_network_length).unwrap_or(cidr)
+                },
+            };
+
+            combiner.push(cidr);
+        }
+
+        combiner
+    }
+
+    /// Get the smallest CIDR which covers all of these CIDRs. Returns `None` if this combiner is empty.
     #[inline]
-    pub fn size(&self) -> u64 {
-        let mut sum = 0;
+    pub fn smallest_covering_cidr(&self) -> Option<Ipv4Cidr> {
+        // the CIDRs are sorted, so the first and the last ones determine the covering CIDR
+        Some(self.0.first()?.common_supernet(self.0.last()?))
+    }
 
-        for cidr in self.0.iter() {
-            sum += cidr.size();
+    /// Get at most `max_entries` CIDRs which cover all of these CIDRs with the fewest extra addresses, respecting the merge policy like `aggregate_to`. This takes O(`len` × `max_entries`) time. Returns `None` if `max_entries` cannot be reached.
+    pub fn covering_cidrs(&self, max_entries: usize) -> Option<Ipv4CidrCombiner> {
+        if self.0.is_empty() {
+            return Some(self.clone());
         }
 
-        sum
+        let (_, nodes) = self.trie().cover(max_entries)?;
+
+        Some(self.combine_trie_nodes(&nodes))
     }
 }
@@ -1,15 +1,38 @@
-use core::ops::Deref;
-use std::net::Ipv6Addr;
+use alloc::vec::Vec;
+use core::{net::Ipv6Addr, ops::Deref};
 
 use cidr::Ipv6Cidr;
+#[cfg(feature = "bigint")]
 use num_bigint::BigUint;
+#[cfg(feature = "bigint")]
 use num_traits::Zero;
-
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use super::{
+    trie::{Trie, TrieNode},
+    MergePolicy,
+};
+#[cfg(feature = "diff")]
+use crate::diff::Ipv6CidrDiff;
+#[cfg(feature = "rayon")]
+use crate::iterator::Ipv6CidrIpv6AddrIterator;
+#[cfg(feature = "registry")]
+use crate::registry::Ipv6SpecialPurposeRegistry;
+#[cfg(feature = "sampler")]
+use crate::sampler::Ipv6CidrSampler;
+#[cfg(feature = "utilization")]
+use crate::utilization::Ipv6Utilization;
+#[cfg(feature = "bigint")]
 use crate::Ipv6CidrSize;
+use crate::{
+    merge::{place, Placement},
+    Ipv6CidrNavigation, Ipv6CidrSizeU128,
+};
 
 /// To combine multiple IPv6 CIDRs to supernetworks.
 #[derive(Debug, Clone)]
-pub struct Ipv6CidrCombiner(Vec<Ipv6Cidr>);
+pub struct Ipv6CidrCombiner(Vec<Ipv6Cidr>, MergePolicy);
 
 impl Default for Ipv6CidrCombiner {
     #[inline]
@@ -31,13 +54,19 @@ impl Ipv6CidrCombiner {
     /// Create a new `Ipv6CidrCombiner` instance.
     #[inline]
     pub const fn new() -> Ipv6CidrCombiner {
-        Ipv6CidrCombiner(Vec::new())
+        Ipv6CidrCombiner(Vec::new(), MergePolicy::new())
+    }
+
+    /// Create a new `Ipv6CidrCombiner` instance with a specific merge policy.
+    #[inline]
+    pub const fn with_policy(policy: MergePolicy) -> Ipv6CidrCombiner {
+        Ipv6CidrCombiner(Vec::new(), policy)
     }
 
     /// Create a new `Ipv6CidrCombiner` instance with a specific capacity.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Ipv6CidrCombiner {
-        Ipv6CidrCombiner(Vec::with_capacity(capacity))
+        Ipv6CidrCombiner(Vec::with_capacity(capacity), MergePolicy::new())
     }
 
     /// Create a new `Ipv6CidrCombiner` instance with an existing array.
@@ -47,127 +76,245 @@ impl Ipv6CidrCombiner {
     /// You must ensure that the input array is ordered.
     #[inline]
     pub const unsafe fn from_ipv6_cidr_vec_unchecked(cidr_vec: Vec<Ipv6Cidr>) -> Ipv6CidrCombiner {
-        Ipv6CidrCombiner(cidr_vec)
+        Ipv6CidrCombiner(cidr_vec, MergePolicy::new())
     }
 
     #[inline]
     pub fn into_ipv6_cidr_vec(self) -> Vec<Ipv6Cidr> {
         self.0
     }
+
+    /// Get the merge policy of this combiner.
+    #[inline]
+    pub const fn policy(&self) -> MergePolicy {
+        self.1
+    }
 }
 
 impl Ipv6CidrCombiner {
     /// Push a CIDR into this combiner.
-    pub fn push(&mut self, mut cidr: Ipv6Cidr) {
-        if let Err(mut index) = self.0.binary_search(&cidr) {
-            if self.0.is_empty() {
-                self.0.push(cidr);
-            } else {
-                let pushable = if index == 0 {
-                    true
-                } else {
-                    let previous_cidr = self.0.get(index - 1).unwrap();
+    pub fn push(&mut self, cidr: Ipv6Cidr) {
+        if let Placement::Replace {
+            start,
+            end,
+            cidr,
+        } = place(&self.0, cidr, |bits| self.1.can_merge(bits))
+        {
+            self.0.splice(start..end, [cidr]);
+        }
+    }
 
-                    !previous_cidr.contains(&cidr.first_address())
-                };
+    /// Check an IPv6 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv6: &Ipv6Addr) -> bool {
+        for cidr in self.0.iter() {
+            if cidr.contains(ipv6) {
+                return true;
+            }
+        }
 
-                if pushable {
-                    loop {
-                        if index == self.0.len() {
-                            break;
-                        }
+        false
+    }
 
-                        let next = self.0.get(index).unwrap();
+    /// Get the total size of CIDRs.
+    #[cfg(feature = "bigint")]
+    #[inline]
+    pub fn size(&self) -> BigUint {
+        let mut sum = BigUint::zero();
 
-                        if cidr.contains(&next.first_address()) {
-                            self.0.remove(index);
-                        } else {
-                            break;
-                        }
-                    }
+        for cidr in self.0.iter() {
+            sum += cidr.size();
+        }
 
-                    let mut merging = true;
+        sum
+    }
 
-                    while merging {
-                        merging = false;
+    /// Get the total size of CIDRs without allocating. Returns `u128::MAX` if the total size is `2^128`.
+    #[inline]
+    pub fn size_u128_saturating(&self) -> u128 {
+        self.0.iter().fold(0u128, |sum, cidr| match cidr.size_u128_checked() {
+            Some(size) => sum.saturating_add(size),
+            None => u128::MAX,
+        })
+    }
 
-                        if index < self.0.len() {
-                            let next_cidr = self.0.get(index).unwrap();
+    /// Remove the addresses of a CIDR from this combiner. Returns the removed parts.
+    pub fn subtract(&mut self, cidr: &Ipv6Cidr) -> Ipv6CidrCombiner {
+        let mut removed = Ipv6CidrCombiner::new();
 
-                            let next_bits = next_cidr.network_length();
-                            let bits = cidr.network_length();
+        let first = cidr.first_address();
+        let last = cidr.last_address();
 
-                            if bits > 0 && bits == next_bits {
-                                let next_prefix: u128 = next_cidr.first_address().into();
-                                let prefix: u128 = cidr.first_address().into();
+        // the CIDRs overlapping `cidr` are contiguous
+        let start = self.0.partition_point(|c| c.last_address() < first);
+        let end = start + self.0[start..].partition_point(|c| c.first_address() <= last);
 
-                                let d = next_prefix ^ prefix;
+        if start == end {
+            return removed;
+        }
 
-                                if d == 1 << (128 - bits) as u128 {
-                                    cidr = Ipv6Cidr::new(prefix.into(), bits - 1).unwrap();
+        let mut remaining = Vec::new();
 
-                                    self.0.remove(index);
+        for entry in self.0[start..end].iter() {
+            if entry.network_length() >= cidr.network_length() {
+                removed.push(*entry);
+            } else {
+                // `entry` contains `cidr`, keep the siblings along the path from `entry` down to `cidr`
+                removed.push(*cidr);
 
-                                    merging = true;
-                                }
-                            }
-                        }
+                for bits in entry.network_length() + 1..=cidr.network_length() {
+                    remaining.push(cidr.supernet(bits).unwrap().sibling().unwrap());
+                }
+            }
+        }
 
-                        if index > 0 {
-                            let index_dec = index - 1;
+        remaining.sort_unstable();
 
-                            let previous_cidr = self.0.get_mut(index_dec).unwrap();
+        self.0.splice(start..end, remaining);
 
-                            let previous_bits = previous_cidr.network_length();
-                            let bits = cidr.network_length();
+        removed
+    }
 
-                            if bits > 0 && bits == previous_bits {
-                                let previous_prefix: u128 = previous_cidr.first_address().into();
-                                let prefix: u128 = cidr.first_address().into();
+    /// Get the addresses of a CIDR which are not in these CIDRs, as aligned CIDRs.
+    pub fn free_blocks(&self, within: &Ipv6Cidr) -> Ipv6CidrCombiner {
+        let mut free = Ipv6CidrCombiner::new();
 
-                                let d = prefix ^ previous_prefix;
+        free.push(*within);
 
-                                if d == 1 << (128 - bits) as u128 {
-                                    self.0.remove(index_dec);
+        let first = within.first_address();
+        let last = within.last_address();
 
-                                    index = index_dec;
+        let start = self.0.partition_point(|c| c.last_address() < first);
 
-                                    cidr = Ipv6Cidr::new(previous_prefix.into(), previous_bits - 1)
-                                        .unwrap();
+        for cidr in self.0[start..].iter().take_while(|c| c.first_address() <= last) {
+            free.subtract(cidr);
+        }
 
-                                    merging = true;
-                                }
-                            }
-                        }
-                    }
+        free
+    }
 
-                    self.0.insert(index, cidr);
-                }
-            }
+    /// Find the lowest free block with a specific network length in a CIDR. Returns `None` if there is no such block.
+    pub fn first_free(&self, within: &Ipv6Cidr, network_length: u8) -> Option<Ipv6Cidr> {
+        if network_length < within.network_length() || network_length > 128 {
+            return None;
         }
+
+        // free blocks are maximal and aligned, so a free block of this length lies at the start of one of them
+        self.free_blocks(within)
+            .iter()
+            .find(|cidr| cidr.network_length() <= network_length)
+            .map(|cidr| Ipv6Cidr::new(cidr.first_address(), network_length).unwrap())
     }
 
-    /// Check an IPv6 whether it is in these CIDRs.
+    /// Iterate all addresses of these CIDRs in parallel.
+    #[cfg(feature = "rayon")]
     #[inline]
-    pub fn contains(&self, ipv6: &Ipv6Addr) -> bool {
-        for cidr in self.0.iter() {
-            if cidr.contains(ipv6) {
-                return true;
+    pub fn par_addrs(&self) -> impl ParallelIterator<Item = Ipv6Addr> + '_ {
+        self.0.par_iter().flat_map(Ipv6CidrIpv6AddrIterator::new)
+    }
+
+    /// Pick `n` distinct addresses uniformly at random from these CIDRs, in ascending order. The same seed always picks the same addresses. Returns `None` if there are fewer than `n` addresses.
+    #[cfg(feature = "sampler")]
+    #[inline]
+    pub fn sample(&self, n: usize, seed: u64) -> Option<Vec<Ipv6Addr>> {
+        Ipv6CidrSampler::sample_combiner(self, n, seed)
+    }
+
+    /// Report how much of a parent CIDR is used by these CIDRs.
+    #[cfg(feature = "utilization")]
+    #[inline]
+    pub fn utilization(&self, parent: &Ipv6Cidr) -> Ipv6Utilization {
+        Ipv6Utilization::new(self, parent)
+    }
+
+    /// Remove the addresses which are not globally reachable according to the IANA special-purpose address registry, such as private-use, loopback and documentation addresses. Returns the removed parts.
+    #[cfg(feature = "registry")]
+    pub fn retain_global(&mut self) -> Ipv6CidrCombiner {
+        let mut removed = Ipv6CidrCombiner::new();
+
+        for cidr in Ipv6SpecialPurposeRegistry::non_global().iter() {
+            for cidr in self.subtract(cidr).iter() {
+                removed.push(*cidr);
             }
         }
 
-        false
+        removed
     }
 
-    /// Get the total size of CIDRs.
+    /// Compare these CIDRs (as the old ones) with another combiner (as the new ones).
+    #[cfg(feature = "diff")]
     #[inline]
-    pub fn size(&self) -> BigUint {
-        let mut sum = BigUint::zero();
+    pub fn diff(&self, new: &Ipv6CidrCombiner) -> Ipv6CidrDiff {
+        Ipv6CidrDiff::new(self, new)
+    }
 
-        for cidr in self.0.iter() {
-            sum += cidr.size();
+    /// Merge CIDRs into covering supernetworks until there are at most `max_entries` CIDRs. Every merge picks the supernetwork which covers the fewest addresses outside these CIDRs. Merging stops as soon as `max_entries` is reached.
+    ///
+    /// The merge policy is respected. Nothing is merged if `merge_siblings` is `false`, no supernetwork is shorter than `min_network_length`, and a supernetwork longer than `max_network_length` is widened to that length.
+    ///
+    /// This is greedy, so more extra addresses than necessary may be covered. Use `covering_cidrs` to get the minimum.
+    ///
+    /// Returns the number of extra addresses which are covered, or `None` (without changing anything) if `max_entries` cannot be reached.
+    pub fn aggregate_to(&mut self, max_entries: usize) -> Option<u128> {
+        if self.0.len() <= max_entries {
+            return Some(0);
         }
 
-        sum
+        let (extra, nodes) = self.trie().aggregate(max_entries)?;
+
+        *self = self.combine_trie_nodes(&nodes);
+
+        Some(extra)
+    }
+
+    /// Build the trie of these CIDRs, which must not be empty.
+    fn trie(&self) -> Trie {
+        let cidr_bits: Vec<u8> = self.0.iter().map(|cidr| cidr.network_length()).collect();
+        let supernet_bits: Vec<u8> = self
+            .0
+            .windows(2)
+            .map(|pair| pair[0].common_supernet(&pair[1]).network_length())
+            .collect();
+
+        Trie::new(128, &cidr_bits, &supernet_bits, self.1)
+    }
+
+    /// Create a combiner with the same policy from the chosen nodes of the trie of these CIDRs.
+    fn combine_trie_nodes(&self, nodes: &[TrieNode]) -> Ipv6CidrCombiner {
+        let mut combiner = Ipv6CidrCombiner::with_policy(self.1);
+
+        for node in nodes {
+            let cidr = match *node {
+                TrieNode::Cidr(index) => self.0[index],
+                TrieNode::Supernet(index) => {
+                    let cidr = self.0[index].common_supernet(&self.0[index + 1]);
+
+                    // a supernetwork longer than the merge policy allows is taken as its widened block
+                    cidr.supernet(self.1.max_network_length).unwrap_or(cidr)
+                },
+            };
+
+            combiner.push(cidr);
+        }
+
+        combiner
+    }
+
+    /// Get the smallest CIDR which covers all of these CIDRs. Returns `None` if this combiner is empty.
+    #[inline]
+    pub fn smallest_covering_cidr(&self) -> Option<Ipv6Cidr> {
+        // the CIDRs are sorted, so the first and the last ones determine the covering CIDR
+        Some(self.0.first()?.common_supernet(self.0.last()?))
+    }
+
+    /// Get at most `max_entries` CIDRs which cover all of these CIDRs with the fewest extra addresses, respecting the merge policy like `aggregate_to`. This takes O(`len` × `max_entries`) time. Returns `None` if `max_entries` cannot be reached.
+    pub fn covering_cidrs(&self, max_entries: usize) -> Option<Ipv6CidrCombiner> {
+        if self.0.is_empty() {
+            return Some(self.clone());
+        }
+
+        let (_, nodes) = self.trie().cover(max_entries)?;
+
+        Some(self.combine_trie_nodes(&nodes))
     }
 }
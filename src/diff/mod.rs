@@ -0,0 +1,77 @@
+//! Compare two combiners, reporting the added and removed address space and how each entry changed.
+
+mod v4;
+mod v6;
+
+use core::fmt::{self, Display, Formatter};
+
+pub use v4::*;
+pub use v6::*;
+
+/// How a group of overlapping old and new entries changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CidrChangeKind {
+    /// An old entry is also a new entry.
+    Unchanged,
+    /// A new entry does not overlap any old entry.
+    Added,
+    /// An old entry does not overlap any new entry.
+    Removed,
+    /// An old entry is contained in a larger new entry.
+    Widened,
+    /// An old entry is replaced by a smaller new entry.
+    Narrowed,
+    /// An old entry is replaced by multiple smaller new entries.
+    Split,
+    /// Multiple old entries are contained in a new entry.
+    Merged,
+}
+
+impl CidrChangeKind {
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CidrChangeKind::Unchanged => "unchanged",
+            CidrChangeKind::Added => "added",
+            CidrChangeKind::Removed => "removed",
+            CidrChangeKind::Widened => "widened",
+            CidrChangeKind::Narrowed => "narrowed",
+            CidrChangeKind::Split => "split",
+            CidrChangeKind::Merged => "merged",
+        }
+    }
+
+    /// Classify a group of overlapping entries by their numbers. Two overlapping CIDRs always contain one another, so either side of a group has at most one entry unless the other side has exactly one.
+    #[inline]
+    pub(crate) fn classify(old: usize, new: usize, equal: bool, widened: bool) -> Self {
+        match (old, new) {
+            (0, _) => CidrChangeKind::Added,
+            (_, 0) => CidrChangeKind::Removed,
+            (1, 1) if equal => CidrChangeKind::Unchanged,
+            (1, 1) if widened => CidrChangeKind::Widened,
+            (1, 1) => CidrChangeKind::Narrowed,
+            (1, _) => CidrChangeKind::Split,
+            _ => CidrChangeKind::Merged,
+        }
+    }
+}
+
+impl Display for CidrChangeKind {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[inline]
+fn write_list<T: Display>(f: &mut Formatter<'_>, cidrs: &[T]) -> fmt::Result {
+    for (i, cidr) in cidrs.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        Display::fmt(cidr, f)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use alloc::{vec, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+use cidr::Ipv4Cidr;
+
+use super::{write_list, CidrChangeKind};
+use crate::{combiner::Ipv4CidrCombiner, Ipv4CidrNavigation};
+
+/// A group of overlapping old and new IPv4 entries, and how they changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipv4CidrChange {
+    pub kind: CidrChangeKind,
+    pub old:  Vec<Ipv4Cidr>,
+    pub new:  Vec<Ipv4Cidr>,
+}
+
+impl Display for Ipv4CidrChange {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.kind.as_str())?;
+
+        if !self.old.is_empty() {
+            f.write_str(" ")?;
+            write_list(f, &self.old)?;
+        }
+
+        if !self.new.is_empty() {
+            f.write_str(if self.old.is_empty() { " " } else { " -> " })?;
+            write_list(f, &self.new)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The differences between two IPv4 combiners.
+#[derive(Debug, Clone)]
+pub struct Ipv4CidrDiff {
+    added:   Ipv4CidrCombiner,
+    removed: Ipv4CidrCombiner,
+    changes: Vec<Ipv4CidrChange>,
+}
+
+impl Ipv4CidrDiff {
+    /// Compare an old combiner with a new one.
+    pub fn new(old: &Ipv4CidrCombiner, new: &Ipv4CidrCombiner) -> Ipv4CidrDiff {
+        let mut changes: Vec<Ipv4CidrChange> = Vec::new();
+
+        let (mut i, mut j) = (0, 0);
+        // the last address of the current group
+        let mut group_last = None;
+
+        // both combiners are sorted and disjoint, so entries which overlap are adjacent in a sweep
+        while i < old.len() || j < new.len() {
+            let take_old = j == new.len()
+                || (i < old.len() && old[i].first_address() <= new[j].first_address());
+
+            let cidr = if take_old { old[i] } else { new[j] };
+
+            let in_group = group_last.is_some_and(|last| cidr.first_address() <= last);
+
+            if !in_group {
+                changes.push(Ipv4CidrChange {
+                    kind: CidrChangeKind::Unchanged,
+                    old:  vec![],
+                    new:  vec![],
+                });
+            }
+
+            group_last = group_last.max(Some(cidr.last_address()));
+
+            let group = changes.last_mut().unwrap();
+
+            if take_old {
+                group.old.push(cidr);
+                i += 1;
+            } else {
+                group.new.push(cidr);
+                j += 1;
+            }
+        }
+
+        let mut added = Ipv4CidrCombiner::with_policy(new.policy());
+        let mut removed = Ipv4CidrCombiner::with_policy(old.policy());
+
+        for change in changes.iter_mut() {
+            let (equal, widened) = match (change.old.first(), change.new.first()) {
+                (Some(old), Some(new)) => (old == new, new.network_length() < old.network_length()),
+                _ => (false, false),
+            };
+
+            change.kind =
+                CidrChangeKind::classify(change.old.len(), change.new.len(), equal, widened);
+
+            // one side of a group is a single entry which contains all entries of the other side
+            let old_contains_new = change.old.len() == 1
+                && change
+                    .new
+                    .iter()
+                    .all(|cidr| cidr.network_length() >= change.old[0].network_length());
+
+            if old_contains_new {
+                push_difference(&mut removed, change.old[0], &change.new);
+            } else {
+                push_difference(&mut added, change.new[0], &change.old);
+            }
+        }
+
+        Ipv4CidrDiff {
+            added,
+            removed,
+            changes,
+        }
+    }
+}
+
+impl Ipv4CidrDiff {
+    /// Get the address space which is in the new combiner but not in the old one.
+    #[inline]
+    pub fn added(&self) -> &Ipv4CidrCombiner {
+        &self.added
+    }
+
+    /// Get the address space which is in the old combiner but not in the new one.
+    #[inline]
+    pub fn removed(&self) -> &Ipv4CidrCombiner {
+        &self.removed
+    }
+
+    /// Get every group of overlapping old and new entries in ascending order, including the unchanged ones.
+    #[inline]
+    pub fn changes(&self) -> &[Ipv4CidrChange] {
+        &self.changes
+    }
+
+    /// Check whether both combiners cover the same address space.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Show one changed group per line, skipping the unchanged ones.
+impl Display for Ipv4CidrDiff {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for change in self.changes.iter().filter(|c| c.kind != CidrChangeKind::Unchanged) {
+            f.write_fmt(format_args!("{change}\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Push the addresses of a CIDR which are not in the sorted and disjoint CIDRs contained in it, by descending into the halves which contain some of them.
+fn push_difference(output: &mut Ipv4CidrCombiner, cidr: Ipv4Cidr, holes: &[Ipv4Cidr]) {
+    match holes {
+        [] => output.push(cidr),
+        [hole] if *hole == cidr => (),
+        _ => {
+            // `cidr` strictly contains the holes, so it has children
+            let (lower, upper) = cidr.children().unwrap();
+
+            let split = holes.partition_point(|hole| hole.first_address() <= lower.last_address());
+
+            push_difference(output, lower, &holes[..split]);
+            push_difference(output, upper, &holes[split..]);
+        },
+    }
+}
@@ -0,0 +1,9 @@
+//! Generate reverse DNS names for IP addresses and the reverse zones covering CIDRs.
+//!
+//! IPv4 zones are delegated on octet boundaries under `in-addr.arpa`, and IPv6 zones on nibble boundaries under `ip6.arpa`. A CIDR whose network length is not on a boundary is covered by multiple zones, except for IPv4 CIDRs with a network length from `25` to `31`, which are named by the [RFC 2317](https://www.rfc-editor.org/rfc/rfc2317) classless delegation convention, `<first octet>/<network length>.<c>.<b>.<a>.in-addr.arpa`.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
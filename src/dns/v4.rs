@@ -0,0 +1,106 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+
+use crate::combiner::Ipv4CidrCombiner;
+
+const SUFFIX: &str = "in-addr.arpa";
+
+/// To generate reverse DNS names for IPv4 addresses and CIDRs.
+#[derive(Debug)]
+pub struct Ipv4CidrReverseDns;
+
+impl Ipv4CidrReverseDns {
+    /// Get the name of the zone made of the first `octets` octets of an address.
+    fn zone_name(bits: u32, octets: u8) -> String {
+        let bytes = bits.to_be_bytes();
+
+        let mut name = String::with_capacity(4 * octets as usize + SUFFIX.len());
+
+        for byte in bytes[..octets as usize].iter().rev() {
+            name.push_str(&byte.to_string());
+            name.push('.');
+        }
+
+        name.push_str(SUFFIX);
+
+        name
+    }
+}
+
+impl Ipv4CidrReverseDns {
+    /// Get the PTR name of an address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    ///
+    /// use cidr_utils::dns::Ipv4CidrReverseDns;
+    ///
+    /// assert_eq!(
+    ///     "4.2.0.192.in-addr.arpa",
+    ///     Ipv4CidrReverseDns::ptr_name(&Ipv4Addr::new(192, 0, 2, 4))
+    /// );
+    /// ```
+    #[inline]
+    pub fn ptr_name(addr: &Ipv4Addr) -> String {
+        Self::zone_name(addr.to_bits(), 4)
+    }
+
+    /// Get the names of the reverse zones which exactly cover a CIDR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use cidr::Ipv4Cidr;
+    /// use cidr_utils::dns::Ipv4CidrReverseDns;
+    ///
+    /// assert_eq!(
+    ///     vec!["2.0.192.in-addr.arpa"],
+    ///     Ipv4CidrReverseDns::zones(&Ipv4Cidr::from_str("192.0.2.0/24").unwrap())
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec!["0.168.192.in-addr.arpa", "1.168.192.in-addr.arpa"],
+    ///     Ipv4CidrReverseDns::zones(
+    ///         &Ipv4Cidr::from_str("192.168.0.0/23").unwrap()
+    ///     )
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec!["64/26.2.0.192.in-addr.arpa"],
+    ///     Ipv4CidrReverseDns::zones(
+    ///         &Ipv4Cidr::from_str("192.0.2.64/26").unwrap()
+    ///     )
+    /// );
+    /// ```
+    pub fn zones(cidr: &Ipv4Cidr) -> Vec<String> {
+        let bits = cidr.network_length();
+        let first = cidr.first_address().to_bits();
+
+        if bits > 24 && bits < 32 {
+            return vec![format!("{}/{}.{}", first as u8, bits, Self::zone_name(first, 3))];
+        }
+
+        let zone_bits = bits.div_ceil(8) * 8;
+        let count = 1u32 << (zone_bits - bits);
+        let step = 1u32.checked_shl(32 - zone_bits as u32).unwrap_or(0);
+
+        (0..count).map(|i| Self::zone_name(first + i * step, zone_bits / 8)).collect()
+    }
+
+    /// Get the names of the reverse zones which exactly cover the CIDRs of a combiner.
+    #[inline]
+    pub fn combiner_zones(combiner: &Ipv4CidrCombiner) -> Vec<String> {
+        combiner.iter().flat_map(Self::zones).collect()
+    }
+}
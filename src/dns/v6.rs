@@ -0,0 +1,95 @@
+use alloc::{string::String, vec::Vec};
+use core::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+
+use crate::combiner::Ipv6CidrCombiner;
+
+const SUFFIX: &str = "ip6.arpa";
+
+/// To generate reverse DNS names for IPv6 addresses and CIDRs.
+#[derive(Debug)]
+pub struct Ipv6CidrReverseDns;
+
+impl Ipv6CidrReverseDns {
+    /// Get the name of the zone made of the first `nibbles` nibbles of an address.
+    fn zone_name(bits: u128, nibbles: u8) -> String {
+        let mut name = String::with_capacity(2 * nibbles as usize + SUFFIX.len());
+
+        for i in (0..nibbles).rev() {
+            let nibble = (bits >> (124 - i as u32 * 4)) & 0xF;
+
+            name.push(char::from_digit(nibble as u32, 16).unwrap());
+            name.push('.');
+        }
+
+        name.push_str(SUFFIX);
+
+        name
+    }
+}
+
+impl Ipv6CidrReverseDns {
+    /// Get the PTR name of an address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{net::Ipv6Addr, str::FromStr};
+    ///
+    /// use cidr_utils::dns::Ipv6CidrReverseDns;
+    ///
+    /// assert_eq!(
+    ///     "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.\
+    ///      arpa",
+    ///     Ipv6CidrReverseDns::ptr_name(
+    ///         &Ipv6Addr::from_str("2001:db8::1").unwrap()
+    ///     )
+    /// );
+    /// ```
+    #[inline]
+    pub fn ptr_name(addr: &Ipv6Addr) -> String {
+        Self::zone_name(addr.to_bits(), 32)
+    }
+
+    /// Get the names of the reverse zones which exactly cover a CIDR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use cidr::Ipv6Cidr;
+    /// use cidr_utils::dns::Ipv6CidrReverseDns;
+    ///
+    /// assert_eq!(
+    ///     vec!["8.b.d.0.1.0.0.2.ip6.arpa"],
+    ///     Ipv6CidrReverseDns::zones(
+    ///         &Ipv6Cidr::from_str("2001:db8::/32").unwrap()
+    ///     )
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec!["0.8.b.d.0.1.0.0.2.ip6.arpa", "1.8.b.d.0.1.0.0.2.ip6.arpa"],
+    ///     Ipv6CidrReverseDns::zones(
+    ///         &Ipv6Cidr::from_str("2001:db8::/35").unwrap()
+    ///     )
+    /// );
+    /// ```
+    pub fn zones(cidr: &Ipv6Cidr) -> Vec<String> {
+        let bits = cidr.network_length();
+        let first = cidr.first_address().to_bits();
+
+        let zone_bits = bits.div_ceil(4) * 4;
+        let count = 1u128 << (zone_bits - bits);
+        let step = 1u128.checked_shl(128 - zone_bits as u32).unwrap_or(0);
+
+        (0..count).map(|i| Self::zone_name(first + i * step, zone_bits / 4)).collect()
+    }
+
+    /// Get the names of the reverse zones which exactly cover the CIDRs of a combiner.
+    #[inline]
+    pub fn combiner_zones(combiner: &Ipv6CidrCombiner) -> Vec<String> {
+        combiner.iter().flat_map(Self::zones).collect()
+    }
+}
@@ -0,0 +1,401 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::{is_identifier, is_plain_name, write_joined, Cidrs, ShellWord};
+use crate::combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner};
+
+/// The default `maxelem` value of `ipset`.
+const IPSET_DEFAULT_MAX_ELEMENTS: usize = 65536;
+
+/// Render CIDRs as an `ipset restore` script which fills a `hash:net` set.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::IpsetRestore};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("192.168.0.0/16").unwrap());
+///
+/// assert_eq!(
+///     "create blocklist hash:net family inet -exist\nadd blocklist \
+///      192.168.0.0/16 -exist\n",
+///     IpsetRestore::new_ipv4(&combiner, "blocklist").unwrap().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IpsetRestore<'a> {
+    cidrs:    Cidrs<'a>,
+    set_name: &'a str,
+    create:   bool,
+}
+
+impl<'a> IpsetRestore<'a> {
+    /// Set names of `ipset` can have at most 31 characters.
+    pub const MAX_NAME_LENGTH: usize = 31;
+
+    /// Create a new `IpsetRestore` instance. Returns `None` if the set name is not valid.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner, set_name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), set_name)
+    }
+
+    /// Create a new `IpsetRestore` instance. Returns `None` if the set name is not valid.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner, set_name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), set_name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, set_name: &'a str) -> Option<Self> {
+        if !is_plain_name(set_name, Self::MAX_NAME_LENGTH) || set_name.starts_with('-') {
+            return None;
+        }
+
+        Some(IpsetRestore {
+            cidrs,
+            set_name,
+            create: true,
+        })
+    }
+
+    /// Whether to emit the `create` command before the `add` commands. The default value is `true`.
+    #[inline]
+    pub const fn create(mut self, create: bool) -> Self {
+        self.create = create;
+
+        self
+    }
+}
+
+impl<'a> Display for IpsetRestore<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = self.set_name;
+
+        if self.create {
+            let family = if self.cidrs.is_ipv4() { "inet" } else { "inet6" };
+
+            f.write_fmt(format_args!("create {name} hash:net family {family}"))?;
+
+            let length = self.cidrs.len();
+
+            if length > IPSET_DEFAULT_MAX_ELEMENTS {
+                f.write_fmt(format_args!(" maxelem {length}"))?;
+            }
+
+            f.write_str(" -exist\n")?;
+        }
+
+        for cidr in self.cidrs.iter() {
+            f.write_fmt(format_args!("add {name} {cidr} -exist\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The address families of `nftables` tables which can hold IP address sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NftFamily {
+    Ip,
+    Ip6,
+    Inet,
+    Bridge,
+    Netdev,
+}
+
+impl NftFamily {
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            NftFamily::Ip => "ip",
+            NftFamily::Ip6 => "ip6",
+            NftFamily::Inet => "inet",
+            NftFamily::Bridge => "bridge",
+            NftFamily::Netdev => "netdev",
+        }
+    }
+}
+
+/// Render CIDRs as `nft` commands which declare an interval set and add the CIDRs as its elements.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::NftSet};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("192.168.0.0/16").unwrap());
+///
+/// assert_eq!(
+///     "add set inet filter blocklist { type ipv4_addr; flags interval; \
+///      }\nadd element inet filter blocklist { 10.0.0.0/8, 192.168.0.0/16 }\n",
+///     NftSet::new_ipv4(&combiner, "filter", "blocklist").unwrap().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NftSet<'a> {
+    cidrs:      Cidrs<'a>,
+    family:     NftFamily,
+    table_name: &'a str,
+    set_name:   &'a str,
+}
+
+impl<'a> NftSet<'a> {
+    /// Table and set names of `nftables` can have at most 255 characters.
+    pub const MAX_NAME_LENGTH: usize = 255;
+
+    /// Create a new `NftSet` instance in the `inet` family. Returns `None` if the table name or the set name is not an identifier.
+    #[inline]
+    pub fn new_ipv4(
+        combiner: &'a Ipv4CidrCombiner,
+        table_name: &'a str,
+        set_name: &'a str,
+    ) -> Option<Self> {
+        Self::new(combiner.into(), table_name, set_name)
+    }
+
+    /// Create a new `NftSet` instance in the `inet` family. Returns `None` if the table name or the set name is not an identifier.
+    #[inline]
+    pub fn new_ipv6(
+        combiner: &'a Ipv6CidrCombiner,
+        table_name: &'a str,
+        set_name: &'a str,
+    ) -> Option<Self> {
+        Self::new(combiner.into(), table_name, set_name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, table_name: &'a str, set_name: &'a str) -> Option<Self> {
+        if !is_identifier(table_name, Self::MAX_NAME_LENGTH)
+            || !is_identifier(set_name, Self::MAX_NAME_LENGTH)
+        {
+            return None;
+        }
+
+        Some(NftSet {
+            cidrs,
+            family: NftFamily::Inet,
+            table_name,
+            set_name,
+        })
+    }
+
+    /// Set the family of the table. The default value is `NftFamily::Inet`.
+    #[inline]
+    pub const fn family(mut self, family: NftFamily) -> Self {
+        self.family = family;
+
+        self
+    }
+}
+
+impl<'a> Display for NftSet<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let family = self.family.as_str();
+        let table = self.table_name;
+        let set = self.set_name;
+        let r#type = if self.cidrs.is_ipv4() { "ipv4_addr" } else { "ipv6_addr" };
+
+        f.write_fmt(format_args!(
+            "add set {family} {table} {set} {{ type {type}; flags interval; }}\n"
+        ))?;
+
+        // nft rejects an empty element list
+        if self.cidrs.len() > 0 {
+            f.write_fmt(format_args!("add element {family} {table} {set} {{ "))?;
+
+            write_joined(f, self.cidrs.iter(), ", ")?;
+
+            f.write_str(" }\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Render CIDRs as `iptables -A` (or `ip6tables -A`) shell command lines, one rule per CIDR.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::Iptables};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+///
+/// assert_eq!(
+///     "iptables -A INPUT -s 10.0.0.0/8 -j DROP\n",
+///     Iptables::new_ipv4(&combiner, "INPUT", "DROP").unwrap().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Iptables<'a> {
+    cidrs:       Cidrs<'a>,
+    chain_name:  &'a str,
+    target:      &'a str,
+    destination: bool,
+}
+
+impl<'a> Iptables<'a> {
+    /// Chain and target names of `iptables` can have at most 28 characters.
+    pub const MAX_NAME_LENGTH: usize = 28;
+
+    /// Create a new `Iptables` instance which matches source addresses. Returns `None` if the chain name or the target is not valid.
+    #[inline]
+    pub fn new_ipv4(
+        combiner: &'a Ipv4CidrCombiner,
+        chain_name: &'a str,
+        target: &'a str,
+    ) -> Option<Self> {
+        Self::new(combiner.into(), chain_name, target)
+    }
+
+    /// Create a new `Iptables` instance which matches source addresses. Returns `None` if the chain name or the target is not valid.
+    #[inline]
+    pub fn new_ipv6(
+        combiner: &'a Ipv6CidrCombiner,
+        chain_name: &'a str,
+        target: &'a str,
+    ) -> Option<Self> {
+        Self::new(combiner.into(), chain_name, target)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, chain_name: &'a str, target: &'a str) -> Option<Self> {
+        for name in [chain_name, target] {
+            if !is_plain_name(name, Self::MAX_NAME_LENGTH) || name.starts_with(['-', '!']) {
+                return None;
+            }
+        }
+
+        Some(Iptables {
+            cidrs,
+            chain_name,
+            target,
+            destination: false,
+        })
+    }
+
+    /// Whether to match destination addresses (`-d`) instead of source addresses (`-s`). The default value is `false`.
+    #[inline]
+    pub const fn destination(mut self, destination: bool) -> Self {
+        self.destination = destination;
+
+        self
+    }
+}
+
+impl<'a> Display for Iptables<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let command = if self.cidrs.is_ipv4() { "iptables" } else { "ip6tables" };
+        let chain = ShellWord(self.chain_name);
+        let target = ShellWord(self.target);
+        let option = if self.destination { "-d" } else { "-s" };
+
+        for cidr in self.cidrs.iter() {
+            f.write_fmt(format_args!("{command} -A {chain} {option} {cidr} -j {target}\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Render CIDRs as a `pf.conf` table definition.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::PfTable};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("192.168.0.0/16").unwrap());
+///
+/// assert_eq!(
+///     "table <blocklist> persist { 10.0.0.0/8, 192.168.0.0/16 }\n",
+///     PfTable::new_ipv4(&combiner, "blocklist").unwrap().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PfTable<'a> {
+    cidrs:      Cidrs<'a>,
+    table_name: &'a str,
+    persist:    bool,
+}
+
+impl<'a> PfTable<'a> {
+    /// Table names of `pf` can have at most 31 characters.
+    pub const MAX_NAME_LENGTH: usize = 31;
+
+    /// Create a new `PfTable` instance. Returns `None` if the table name is not valid.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner, table_name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), table_name)
+    }
+
+    /// Create a new `PfTable` instance. Returns `None` if the table name is not valid.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner, table_name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), table_name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, table_name: &'a str) -> Option<Self> {
+        if !is_plain_name(table_name, Self::MAX_NAME_LENGTH)
+            || table_name.contains(['<', '>', '{', '}', ',', '#'])
+        {
+            return None;
+        }
+
+        Some(PfTable {
+            cidrs,
+            table_name,
+            persist: true,
+        })
+    }
+
+    /// Whether to add the `persist` flag, which keeps the table even when no rule refers to it. The default value is `true`.
+    #[inline]
+    pub const fn persist(mut self, persist: bool) -> Self {
+        self.persist = persist;
+
+        self
+    }
+}
+
+impl<'a> Display for PfTable<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("table <{}>", self.table_name))?;
+
+        if self.persist {
+            f.write_str(" persist")?;
+        }
+
+        f.write_str(" {")?;
+
+        if self.cidrs.len() > 0 {
+            f.write_str(" ")?;
+
+            write_joined(f, self.cidrs.iter(), ", ")?;
+        }
+
+        f.write_str(" }\n")
+    }
+}
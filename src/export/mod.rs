@@ -0,0 +1,165 @@
+//! Render combined CIDRs as configurations of other tools.
+
+mod firewall;
+mod router;
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+pub use firewall::*;
+pub use router::*;
+
+use crate::combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner};
+
+#[derive(Debug, Clone, Copy)]
+enum Cidrs<'a> {
+    V4(&'a [Ipv4Cidr]),
+    V6(&'a [Ipv6Cidr]),
+}
+
+impl<'a> From<&'a Ipv4CidrCombiner> for Cidrs<'a> {
+    #[inline]
+    fn from(combiner: &'a Ipv4CidrCombiner) -> Self {
+        Cidrs::V4(combiner)
+    }
+}
+
+impl<'a> From<&'a Ipv6CidrCombiner> for Cidrs<'a> {
+    #[inline]
+    fn from(combiner: &'a Ipv6CidrCombiner) -> Self {
+        Cidrs::V6(combiner)
+    }
+}
+
+impl<'a> Cidrs<'a> {
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Cidrs::V4(cidrs) => cidrs.len(),
+            Cidrs::V6(cidrs) => cidrs.len(),
+        }
+    }
+
+    #[inline]
+    fn is_ipv4(&self) -> bool {
+        matches!(self, Cidrs::V4(_))
+    }
+
+    #[inline]
+    fn max_network_length(&self) -> u8 {
+        if self.is_ipv4() {
+            32
+        } else {
+            128
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> impl Iterator<Item = DisplayCidr<'a>> {
+        let (v4, v6): (&[Ipv4Cidr], &[Ipv6Cidr]) = match *self {
+            Cidrs::V4(cidrs) => (cidrs, &[]),
+            Cidrs::V6(cidrs) => (&[], cidrs),
+        };
+
+        v4.iter().map(DisplayCidr::V4).chain(v6.iter().map(DisplayCidr::V6))
+    }
+}
+
+/// Always show the network length, even for host addresses.
+#[derive(Debug, Clone, Copy)]
+enum DisplayCidr<'a> {
+    V4(&'a Ipv4Cidr),
+    V6(&'a Ipv6Cidr),
+}
+
+impl<'a> DisplayCidr<'a> {
+    #[inline]
+    fn network_length(&self) -> u8 {
+        match self {
+            DisplayCidr::V4(cidr) => cidr.network_length(),
+            DisplayCidr::V6(cidr) => cidr.network_length(),
+        }
+    }
+}
+
+impl<'a> Display for DisplayCidr<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplayCidr::V4(cidr) => {
+                f.write_fmt(format_args!("{}/{}", cidr.first_address(), cidr.network_length()))
+            },
+            DisplayCidr::V6(cidr) => {
+                f.write_fmt(format_args!("{}/{}", cidr.first_address(), cidr.network_length()))
+            },
+        }
+    }
+}
+
+/// Write elements separated by `separator`.
+fn write_joined<T: Display>(
+    f: &mut Formatter<'_>,
+    elements: impl Iterator<Item = T>,
+    separator: &str,
+) -> fmt::Result {
+    for (index, element) in elements.enumerate() {
+        if index > 0 {
+            f.write_str(separator)?;
+        }
+
+        f.write_fmt(format_args!("{element}"))?;
+    }
+
+    Ok(())
+}
+
+/// Check whether a name is not empty, not longer than `max_length` and only consists of ASCII graphic characters other than quotes and backslashes.
+#[inline]
+fn is_plain_name(name: &str, max_length: usize) -> bool {
+    !name.is_empty()
+        && name.len() <= max_length
+        && name.bytes().all(|b| b.is_ascii_graphic() && !matches!(b, b'"' | b'\'' | b'\\'))
+}
+
+/// Check whether a name is an identifier which starts with a letter or an underscore and continues with letters, digits or underscores.
+#[inline]
+fn is_identifier(name: &str, max_length: usize) -> bool {
+    let bytes = name.as_bytes();
+
+    !bytes.is_empty()
+        && bytes.len() <= max_length
+        && (bytes[0].is_ascii_alphabetic() || bytes[0] == b'_')
+        && bytes.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'_')
+}
+
+/// A word which is single-quoted when it contains characters a POSIX shell would interpret.
+struct ShellWord<'a>(&'a str);
+
+impl<'a> Display for ShellWord<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let safe = !self.0.is_empty()
+            && self.0.bytes().all(|b| {
+                b.is_ascii_alphanumeric()
+                    || matches!(
+                        b,
+                        b'_' | b'-' | b'.' | b':' | b'+' | b'/' | b'@' | b'%' | b'=' | b','
+                    )
+            });
+
+        if safe {
+            return f.write_str(self.0);
+        }
+
+        f.write_char('\'')?;
+
+        for (index, part) in self.0.split('\'').enumerate() {
+            if index > 0 {
+                f.write_str("'\\''")?;
+            }
+
+            f.write_str(part)?;
+        }
+
+        f.write_char('\'')
+    }
+}
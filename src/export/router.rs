@@ -0,0 +1,431 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::{is_identifier, is_plain_name, Cidrs};
+use crate::combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner};
+
+/// The action of an entry in a prefix list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixListAction {
+    Permit,
+    Deny,
+}
+
+impl PrefixListAction {
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PrefixListAction::Permit => "permit",
+            PrefixListAction::Deny => "deny",
+        }
+    }
+}
+
+/// Render CIDRs as Cisco IOS `ip prefix-list` (or `ipv6 prefix-list`) statements.
+///
+/// The `ge` and `le` options apply to every entry. On an entry whose network length already reaches `ge`, the range is kept open with `le` (the address length if `le` is not set), and `le` is omitted on an entry whose network length reaches it. An empty combiner renders no statements.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::CiscoPrefixList};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+///
+/// assert_eq!(
+///     "ip prefix-list CUSTOMER seq 5 permit 10.0.0.0/8 le 24\nip \
+///      prefix-list CUSTOMER seq 10 permit 192.168.1.0/24\n",
+///     CiscoPrefixList::new_ipv4(&combiner, "CUSTOMER")
+///         .unwrap()
+///         .le(24)
+///         .unwrap()
+///         .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CiscoPrefixList<'a> {
+    cidrs:    Cidrs<'a>,
+    name:     &'a str,
+    action:   PrefixListAction,
+    sequence: Option<(u32, u32)>,
+    ge:       Option<u8>,
+    le:       Option<u8>,
+}
+
+impl<'a> CiscoPrefixList<'a> {
+    /// Prefix list names of Cisco IOS can have at most 63 characters.
+    pub const MAX_NAME_LENGTH: usize = 63;
+
+    /// Create a new `CiscoPrefixList` instance. Returns `None` if the name is not valid.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    /// Create a new `CiscoPrefixList` instance. Returns `None` if the name is not valid.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, name: &'a str) -> Option<Self> {
+        if !is_plain_name(name, Self::MAX_NAME_LENGTH) {
+            return None;
+        }
+
+        Some(CiscoPrefixList {
+            cidrs,
+            name,
+            action: PrefixListAction::Permit,
+            sequence: Some((5, 5)),
+            ge: None,
+            le: None,
+        })
+    }
+
+    /// Set the action of every entry. The default value is `PrefixListAction::Permit`.
+    #[inline]
+    pub const fn action(mut self, action: PrefixListAction) -> Self {
+        self.action = action;
+
+        self
+    }
+
+    /// Number the entries with `seq`, starting from `start` and increasing by `step`. The default numbering starts from `5` and increases by `5`.
+    #[inline]
+    pub const fn sequence(mut self, start: u32, step: u32) -> Self {
+        self.sequence = Some((start, step));
+
+        self
+    }
+
+    /// Do not number the entries, and let the router assign the sequence numbers.
+    #[inline]
+    pub const fn no_sequence(mut self) -> Self {
+        self.sequence = None;
+
+        self
+    }
+
+    /// Also match longer prefixes whose network lengths are at least `ge`. The value is capped to the address length. Returns `None` if `le` is set and smaller than `ge`.
+    #[inline]
+    pub const fn ge(mut self, ge: u8) -> Option<Self> {
+        if let Some(le) = self.le {
+            if le < ge {
+                return None;
+            }
+        }
+
+        self.ge = Some(ge);
+
+        Some(self)
+    }
+
+    /// Also match longer prefixes whose network lengths are at most `le`. The value is capped to the address length. Returns `None` if `ge` is set and larger than `le`.
+    #[inline]
+    pub const fn le(mut self, le: u8) -> Option<Self> {
+        if let Some(ge) = self.ge {
+            if le < ge {
+                return None;
+            }
+        }
+
+        self.le = Some(le);
+
+        Some(self)
+    }
+}
+
+impl<'a> Display for CiscoPrefixList<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let command = if self.cidrs.is_ipv4() { "ip" } else { "ipv6" };
+        let name = self.name;
+        let action = self.action.as_str();
+        let max_network_length = self.cidrs.max_network_length();
+
+        let mut sequence = self.sequence;
+
+        for cidr in self.cidrs.iter() {
+            f.write_fmt(format_args!("{command} prefix-list {name}"))?;
+
+            if let Some((number, step)) = sequence.as_mut() {
+                f.write_fmt(format_args!(" seq {number}"))?;
+
+                *number = number.saturating_add(*step);
+            }
+
+            f.write_fmt(format_args!(" {action} {cidr}"))?;
+
+            // Cisco IOS requires `length < ge <= le`
+            let network_length = cidr.network_length();
+
+            let ge = self.ge.map(|ge| ge.min(max_network_length)).filter(|ge| *ge > network_length);
+
+            // an entry without `le` matches up to the address length only if `ge` is given, so keep the range open when `ge` is dropped
+            let le = match (self.le, self.ge) {
+                (Some(le), _) => Some(le.min(max_network_length)),
+                (None, Some(_)) if ge.is_none() => Some(max_network_length),
+                (None, _) => None,
+            }
+            .filter(|le| *le > network_length);
+
+            if let Some(ge) = ge {
+                f.write_fmt(format_args!(" ge {ge}"))?;
+            }
+
+            if let Some(le) = le {
+                f.write_fmt(format_args!(" le {le}"))?;
+            }
+
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Render CIDRs as a Juniper `prefix-list` stanza under `policy-options`. An empty combiner renders an empty stanza, which matches nothing.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::JuniperPrefixList};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+///
+/// assert_eq!(
+///     "prefix-list customer {\n    10.0.0.0/8;\n}\n",
+///     JuniperPrefixList::new_ipv4(&combiner, "customer").unwrap().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JuniperPrefixList<'a> {
+    cidrs: Cidrs<'a>,
+    name:  &'a str,
+}
+
+impl<'a> JuniperPrefixList<'a> {
+    /// Prefix list names of Junos can have at most 255 characters.
+    pub const MAX_NAME_LENGTH: usize = 255;
+
+    /// Create a new `JuniperPrefixList` instance. Returns `None` if the name is not valid.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    /// Create a new `JuniperPrefixList` instance. Returns `None` if the name is not valid.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, name: &'a str) -> Option<Self> {
+        if !is_plain_name(name, Self::MAX_NAME_LENGTH) || name.contains(['{', '}', ';', '#']) {
+            return None;
+        }
+
+        Some(JuniperPrefixList {
+            cidrs,
+            name,
+        })
+    }
+}
+
+impl<'a> Display for JuniperPrefixList<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("prefix-list {} {{\n", self.name))?;
+
+        for cidr in self.cidrs.iter() {
+            f.write_fmt(format_args!("    {cidr};\n"))?;
+        }
+
+        f.write_str("}\n")
+    }
+}
+
+/// Render CIDRs as Juniper `route-filter` statements, to be placed in the `from` stanza of a policy term.
+///
+/// Every entry matches `exact`, or `upto` the maximum length if one is set and the entry is shorter than it. An empty combiner renders no statements.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::JuniperRouteFilter};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+///
+/// assert_eq!(
+///     "route-filter 10.0.0.0/8 upto /24;\nroute-filter 192.168.1.0/24 \
+///      exact;\n",
+///     JuniperRouteFilter::new_ipv4(&combiner).max_length(24).to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JuniperRouteFilter<'a> {
+    cidrs:      Cidrs<'a>,
+    max_length: Option<u8>,
+}
+
+impl<'a> JuniperRouteFilter<'a> {
+    /// Create a new `JuniperRouteFilter` instance.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner) -> Self {
+        Self::new(combiner.into())
+    }
+
+    /// Create a new `JuniperRouteFilter` instance.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner) -> Self {
+        Self::new(combiner.into())
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>) -> Self {
+        JuniperRouteFilter {
+            cidrs,
+            max_length: None,
+        }
+    }
+
+    /// Also match longer prefixes whose network lengths are at most `max_length`. The value is capped to the address length.
+    #[inline]
+    pub const fn max_length(mut self, max_length: u8) -> Self {
+        self.max_length = Some(max_length);
+
+        self
+    }
+}
+
+impl<'a> Display for JuniperRouteFilter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let max_network_length = self.cidrs.max_network_length();
+
+        for cidr in self.cidrs.iter() {
+            let upto = self
+                .max_length
+                .map(|max_length| max_length.min(max_network_length))
+                .filter(|max_length| *max_length > cidr.network_length());
+
+            match upto {
+                Some(upto) => f.write_fmt(format_args!("route-filter {cidr} upto /{upto};\n"))?,
+                None => f.write_fmt(format_args!("route-filter {cidr} exact;\n"))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Render CIDRs as a BIRD prefix set constant. BIRD does not accept an empty prefix set literal, so an empty combiner cannot be rendered.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{combiner::Ipv4CidrCombiner, export::BirdPrefixSet};
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+///
+/// assert_eq!(
+///     "define CUSTOMER = [ 10.0.0.0/8{8,24}, 192.168.1.0/24 ];\n",
+///     BirdPrefixSet::new_ipv4(&combiner, "CUSTOMER")
+///         .unwrap()
+///         .max_length(24)
+///         .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BirdPrefixSet<'a> {
+    cidrs:      Cidrs<'a>,
+    name:       &'a str,
+    max_length: Option<u8>,
+}
+
+impl<'a> BirdPrefixSet<'a> {
+    /// BIRD symbols can have at most 64 characters.
+    pub const MAX_NAME_LENGTH: usize = 64;
+
+    /// Create a new `BirdPrefixSet` instance. Returns `None` if the name is not an identifier or the combiner is empty.
+    #[inline]
+    pub fn new_ipv4(combiner: &'a Ipv4CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    /// Create a new `BirdPrefixSet` instance. Returns `None` if the name is not an identifier or the combiner is empty.
+    #[inline]
+    pub fn new_ipv6(combiner: &'a Ipv6CidrCombiner, name: &'a str) -> Option<Self> {
+        Self::new(combiner.into(), name)
+    }
+
+    #[inline]
+    fn new(cidrs: Cidrs<'a>, name: &'a str) -> Option<Self> {
+        if !is_identifier(name, Self::MAX_NAME_LENGTH) || cidrs.len() == 0 {
+            return None;
+        }
+
+        Some(BirdPrefixSet {
+            cidrs,
+            name,
+            max_length: None,
+        })
+    }
+
+    /// Also match longer prefixes whose network lengths are at most `max_length`. The value is capped to the address length.
+    #[inline]
+    pub const fn max_length(mut self, max_length: u8) -> Self {
+        self.max_length = Some(max_length);
+
+        self
+    }
+}
+
+impl<'a> Display for BirdPrefixSet<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let max_network_length = self.cidrs.max_network_length();
+
+        f.write_fmt(format_args!("define {} = [", self.name))?;
+
+        for (index, cidr) in self.cidrs.iter().enumerate() {
+            let separator = if index > 0 { ", " } else { " " };
+
+            f.write_fmt(format_args!("{separator}{cidr}"))?;
+
+            let network_length = cidr.network_length();
+
+            let max_length = self
+                .max_length
+                .map(|max_length| max_length.min(max_network_length))
+                .filter(|max_length| *max_length > network_length);
+
+            if let Some(max_length) = max_length {
+                f.write_fmt(format_args!("{{{network_length},{max_length}}}"))?;
+            }
+        }
+
+        f.write_str(" ];\n")
+    }
+}
@@ -1,5 +1,11 @@
+#[cfg(feature = "rayon")]
+mod parallel;
+mod step_by;
 mod v4;
 mod v6;
 
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use step_by::*;
 pub use v4::*;
 pub use v6::*;
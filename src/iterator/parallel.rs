@@ -0,0 +1,72 @@
+use rayon::iter::{
+    plumbing::{bridge_unindexed, Folder, UnindexedConsumer, UnindexedProducer},
+    IntoParallelIterator, ParallelIterator,
+};
+
+use super::*;
+
+/// To iterate the addresses of a CIDR iterator in parallel. It is created by `into_par_iter`.
+#[derive(Debug, Clone)]
+pub struct ParallelCidrIterator<I> {
+    iter: I,
+}
+
+/// Splits the remaining addresses of an iterator by offset.
+struct CidrProducer<I>(I);
+
+macro_rules! impl_parallel {
+    ($($iter:ty),* $(,)?) => {
+        $(
+            impl UnindexedProducer for CidrProducer<$iter> {
+                type Item = <$iter as Iterator>::Item;
+
+                #[inline]
+                fn split(mut self) -> (Self, Option<Self>) {
+                    let upper = self.0.split_off();
+
+                    (self, upper.map(CidrProducer))
+                }
+
+                #[inline]
+                fn fold_with<F>(self, folder: F) -> F
+                where
+                    F: Folder<Self::Item>, {
+                    folder.consume_iter(self.0)
+                }
+            }
+
+            impl ParallelIterator for ParallelCidrIterator<$iter> {
+                type Item = <$iter as Iterator>::Item;
+
+                #[inline]
+                fn drive_unindexed<C>(self, consumer: C) -> C::Result
+                where
+                    C: UnindexedConsumer<Self::Item>, {
+                    bridge_unindexed(CidrProducer(self.iter), consumer)
+                }
+            }
+
+            impl IntoParallelIterator for $iter {
+                type Item = <$iter as Iterator>::Item;
+                type Iter = ParallelCidrIterator<$iter>;
+
+                #[inline]
+                fn into_par_iter(self) -> Self::Iter {
+                    ParallelCidrIterator {
+                        iter: self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_parallel!(
+    Ipv4CidrU8ArrayIterator,
+    Ipv4CidrIterator,
+    Ipv4CidrIpv4AddrIterator,
+    Ipv6CidrU8ArrayIterator,
+    Ipv6CidrU16ArrayIterator,
+    Ipv6CidrIterator,
+    Ipv6CidrIpv6AddrIterator,
+);
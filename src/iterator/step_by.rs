@@ -0,0 +1,89 @@
+use super::*;
+
+/// To iterate every `step`-th address of a CIDR iterator. It is created by the `step_by_u64` method of the IPv4 CIDR iterators or the `step_by_u128` method of the IPv6 CIDR iterators.
+///
+/// Unlike `Iterator::step_by`, the step can be as large as the address space, and each item is reached with a single seek instead of stepping through the skipped addresses.
+#[derive(Debug, Clone)]
+pub struct CidrStepBy<I, S> {
+    iter:       I,
+    step:       S,
+    first_take: bool,
+}
+
+macro_rules! impl_step_by {
+    ($step:ty, $step_by:ident, $nth:ident; $($iter:ty),* $(,)?) => {
+        $(
+            impl $iter {
+                /// Create an iterator starting at the same point, but stepping by the given amount at each iteration. This is `Iterator::step_by` with a step which can be as large as the address space.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `step` is `0`.
+                #[inline]
+                pub fn $step_by(self, step: $step) -> CidrStepBy<$iter, $step> {
+                    assert!(step != 0, "the step must not be zero");
+
+                    CidrStepBy {
+                        iter: self, step, first_take: true
+                    }
+                }
+            }
+
+            impl Iterator for CidrStepBy<$iter, $step> {
+                type Item = <$iter as Iterator>::Item;
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.first_take {
+                        self.first_take = false;
+
+                        self.iter.next()
+                    } else {
+                        self.iter.$nth(self.step - 1)
+                    }
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let (low, high) = self.iter.size_hint();
+
+                    let step = usize::try_from(self.step).unwrap_or(usize::MAX);
+
+                    let count = |n: usize| {
+                        if !self.first_take {
+                            n / step
+                        } else if n == 0 {
+                            0
+                        } else {
+                            1 + (n - 1) / step
+                        }
+                    };
+
+                    (count(low), high.map(count))
+                }
+
+                fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                    let offset = match <$step>::try_from(n).ok().and_then(|n| n.checked_mul(self.step)) {
+                        Some(offset) if self.first_take => Some(offset),
+                        Some(offset) => offset.checked_add(self.step - 1),
+                        None => None,
+                    };
+
+                    self.first_take = false;
+
+                    // an offset which overflows is beyond every CIDR, so seeking to the maximum exhausts the iterator
+                    self.iter.$nth(offset.unwrap_or(<$step>::MAX))
+                }
+            }
+        )*
+    };
+}
+
+impl_step_by!(u64, step_by_u64, nth_u64; Ipv4CidrU8ArrayIterator, Ipv4CidrIterator, Ipv4CidrIpv4AddrIterator);
+
+impl_step_by!(
+    u128, step_by_u128, nth_u128;
+    Ipv6CidrU8ArrayIterator,
+    Ipv6CidrU16ArrayIterator,
+    Ipv6CidrIterator,
+    Ipv6CidrIpv6AddrIterator,
+);
@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use core::net::Ipv4Addr;
 
 use cidr::Ipv4Cidr;
 
@@ -7,7 +7,7 @@ use crate::Ipv4CidrSize;
 // TODO: Ipv4CidrU8ArrayIterator
 
 /// To iterate IPv4 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv4CidrU8ArrayIterator {
     from: u32,
     size: u64,
@@ -51,7 +51,7 @@ impl Ipv4CidrU8ArrayIterator {
 
     #[inline]
     pub fn nth_u64(&mut self, n: u64) -> Option<[u8; 4]> {
-        self.next += n;
+        self.next = self.next.saturating_add(n);
 
         if self.next < self.back {
             Some(unsafe { self.next_unchecked() })
@@ -76,6 +76,24 @@ impl Ipv4CidrU8ArrayIterator {
 
         None
     }
+
+    /// Split off the upper half of the remaining addresses, seeking the new iterator to its first address.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        let half = self.back.saturating_sub(self.next) / 2;
+
+        if half == 0 {
+            return None;
+        }
+
+        let mut upper = self.clone();
+
+        upper.nth_u64(half - 1);
+
+        self.back = upper.next;
+
+        Some(upper)
+    }
 }
 
 impl Iterator for Ipv4CidrU8ArrayIterator {
@@ -154,7 +172,7 @@ impl DoubleEndedIterator for Ipv4CidrU8ArrayIterator {
 // TODO: Ipv4CidrIterator
 
 /// To iterate IPv4 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv4CidrIterator {
     iter: Ipv4CidrU8ArrayIterator,
 }
@@ -173,6 +191,14 @@ impl Ipv4CidrIterator {
     pub fn nth_u64(&mut self, n: u64) -> Option<u32> {
         self.iter.nth_u64(n).map(u32::from_be_bytes)
     }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        self.iter.split_off().map(|iter| Self {
+            iter,
+        })
+    }
 }
 
 impl Iterator for Ipv4CidrIterator {
@@ -209,7 +235,7 @@ impl DoubleEndedIterator for Ipv4CidrIterator {
 // TODO: Ipv4CidrIpv4AddrIterator
 
 /// To iterate IPv4 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv4CidrIpv4AddrIterator {
     iter: Ipv4CidrU8ArrayIterator,
 }
@@ -228,6 +254,14 @@ impl Ipv4CidrIpv4AddrIterator {
     pub fn nth_u64(&mut self, n: u64) -> Option<Ipv4Addr> {
         self.iter.nth_u64(n).map(|a| a.into())
     }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        self.iter.split_off().map(|iter| Self {
+            iter,
+        })
+    }
 }
 
 impl Iterator for Ipv4CidrIpv4AddrIterator {
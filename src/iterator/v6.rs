@@ -1,82 +1,144 @@
-use std::net::Ipv6Addr;
+use core::net::Ipv6Addr;
 
 use cidr::Ipv6Cidr;
+#[cfg(feature = "bigint")]
 use num_bigint::BigUint;
-use num_traits::{One, ToPrimitive, Zero};
-
-use crate::Ipv6CidrSize;
+#[cfg(feature = "bigint")]
+use num_traits::ToPrimitive;
 
 // TODO: Ipv6CidrU8ArrayIterator
 
 /// To iterate IPv6 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv6CidrU8ArrayIterator {
     from: u128,
-    size: BigUint,
-    next: BigUint,
-    back: BigUint,
+    next: u128,
+    // inclusive
+    back: u128,
+    done: bool,
 }
 
 impl Ipv6CidrU8ArrayIterator {
     #[inline]
     pub fn new(cidr: &Ipv6Cidr) -> Self {
         let from: u128 = cidr.first_address().into();
-        let size = cidr.size();
+        let back = u128::MAX.checked_shr(cidr.network_length() as u32).unwrap_or(0);
 
         Self {
             from,
-            size: size.clone(),
-            next: BigUint::zero(),
-            back: size,
+            next: 0,
+            back,
+            done: false,
         }
     }
 }
 
 impl Ipv6CidrU8ArrayIterator {
     #[inline]
-    unsafe fn next_unchecked(&mut self) -> [u8; 16] {
-        let p = self.from + self.next.to_u128().unwrap();
+    fn next_unchecked(&mut self) -> [u8; 16] {
+        let p = self.from + self.next;
 
-        self.next += BigUint::one();
+        if self.next == self.back {
+            self.done = true;
+        } else {
+            self.next += 1;
+        }
 
         p.to_be_bytes()
     }
 
     #[inline]
-    unsafe fn next_back_unchecked(&mut self) -> [u8; 16] {
-        self.back -= BigUint::one();
+    fn next_back_unchecked(&mut self) -> [u8; 16] {
+        let p = self.from + self.back;
 
-        let p = self.from + self.back.to_u128().unwrap();
+        if self.next == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
+        }
 
         p.to_be_bytes()
     }
 
     #[inline]
-    pub fn nth_big_uint(&mut self, n: BigUint) -> Option<[u8; 16]> {
-        self.next += n;
+    pub fn nth_u128(&mut self, n: u128) -> Option<[u8; 16]> {
+        if !self.done {
+            match self.next.checked_add(n) {
+                Some(next) if next <= self.back => {
+                    self.next = next;
 
-        if self.next < self.back {
-            Some(unsafe { self.next_unchecked() })
-        } else {
-            self.next = self.size.clone();
+                    return Some(self.next_unchecked());
+                },
+                _ => self.done = true,
+            }
+        }
 
-            None
+        None
+    }
+
+    #[inline]
+    pub fn nth_back_u128(&mut self, n: u128) -> Option<[u8; 16]> {
+        if !self.done {
+            match self.back.checked_sub(n) {
+                Some(back) if back >= self.next => {
+                    self.back = back;
+
+                    return Some(self.next_back_unchecked());
+                },
+                _ => self.done = true,
+            }
         }
+
+        None
     }
 
+    #[cfg(feature = "bigint")]
+    #[inline]
+    pub fn nth_big_uint(&mut self, n: BigUint) -> Option<[u8; 16]> {
+        match n.to_u128() {
+            Some(n) => self.nth_u128(n),
+            None => {
+                self.done = true;
+
+                None
+            },
+        }
+    }
+
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_back_big_uint(&mut self, n: BigUint) -> Option<[u8; 16]> {
-        if self.back > n {
-            self.back -= n;
+        match n.to_u128() {
+            Some(n) => self.nth_back_u128(n),
+            None => {
+                self.done = true;
 
-            if self.next < self.back {
-                return Some(unsafe { self.next_back_unchecked() });
-            }
+                None
+            },
+        }
+    }
+
+    /// Split off the upper half of the remaining addresses, seeking the new iterator to its first address.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        if self.done {
+            return None;
         }
 
-        self.next = self.size.clone();
+        let distance = self.back - self.next;
+        let half = distance / 2 + (distance & 1);
 
-        None
+        if half == 0 {
+            return None;
+        }
+
+        let mut upper = self.clone();
+
+        upper.nth_u128(half - 1);
+
+        self.back = upper.next - 1;
+
+        Some(upper)
     }
 }
 
@@ -85,117 +147,179 @@ impl Iterator for Ipv6CidrU8ArrayIterator {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            Some(unsafe { self.next_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            Some(self.next_unchecked())
         }
     }
 
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            self.next = self.back.clone() - BigUint::one();
-
-            Some(unsafe { self.next_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            self.next = self.back;
+
+            Some(self.next_unchecked())
         }
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.nth_big_uint(BigUint::from(n))
+        self.nth_u128(n as u128)
     }
 }
 
 impl DoubleEndedIterator for Ipv6CidrU8ArrayIterator {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            Some(unsafe { self.next_back_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            Some(self.next_back_unchecked())
         }
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.nth_back_big_uint(BigUint::from(n))
+        self.nth_back_u128(n as u128)
     }
 }
 
 // TODO: Ipv6CidrU8ArrayIterator
 
 /// To iterate IPv6 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv6CidrU16ArrayIterator {
     from: u128,
-    next: BigUint,
-    back: BigUint,
-    size: BigUint,
+    next: u128,
+    // inclusive
+    back: u128,
+    done: bool,
 }
 
 impl Ipv6CidrU16ArrayIterator {
     #[inline]
     pub fn new(cidr: &Ipv6Cidr) -> Self {
         let from: u128 = cidr.first_address().into();
-        let size = cidr.size();
+        let back = u128::MAX.checked_shr(cidr.network_length() as u32).unwrap_or(0);
 
         Self {
             from,
-            size: size.clone(),
-            next: BigUint::zero(),
-            back: size,
+            next: 0,
+            back,
+            done: false,
         }
     }
 }
 
 impl Ipv6CidrU16ArrayIterator {
     #[inline]
-    unsafe fn next_unchecked(&mut self) -> [u16; 8] {
-        let p = self.from + self.next.to_u128().unwrap();
+    fn next_unchecked(&mut self) -> [u16; 8] {
+        let p = self.from + self.next;
 
-        self.next += BigUint::one();
+        if self.next == self.back {
+            self.done = true;
+        } else {
+            self.next += 1;
+        }
 
         u128_to_u16_array(p)
     }
 
     #[inline]
-    unsafe fn next_back_unchecked(&mut self) -> [u16; 8] {
-        self.back -= BigUint::one();
+    fn next_back_unchecked(&mut self) -> [u16; 8] {
+        let p = self.from + self.back;
 
-        let p = self.from + self.back.to_u128().unwrap();
+        if self.next == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
+        }
 
         u128_to_u16_array(p)
     }
 
     #[inline]
-    pub fn nth_big_uint(&mut self, n: BigUint) -> Option<[u16; 8]> {
-        self.next += n;
+    pub fn nth_u128(&mut self, n: u128) -> Option<[u16; 8]> {
+        if !self.done {
+            match self.next.checked_add(n) {
+                Some(next) if next <= self.back => {
+                    self.next = next;
 
-        if self.next < self.back {
-            Some(unsafe { self.next_unchecked() })
-        } else {
-            self.next = self.size.clone();
+                    return Some(self.next_unchecked());
+                },
+                _ => self.done = true,
+            }
+        }
 
-            None
+        None
+    }
+
+    #[inline]
+    pub fn nth_back_u128(&mut self, n: u128) -> Option<[u16; 8]> {
+        if !self.done {
+            match self.back.checked_sub(n) {
+                Some(back) if back >= self.next => {
+                    self.back = back;
+
+                    return Some(self.next_back_unchecked());
+                },
+                _ => self.done = true,
+            }
+        }
+
+        None
+    }
+
+    #[cfg(feature = "bigint")]
+    #[inline]
+    pub fn nth_big_uint(&mut self, n: BigUint) -> Option<[u16; 8]> {
+        match n.to_u128() {
+            Some(n) => self.nth_u128(n),
+            None => {
+                self.done = true;
+
+                None
+            },
         }
     }
 
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_back_big_uint(&mut self, n: BigUint) -> Option<[u16; 8]> {
-        if self.back > n {
-            self.back -= n;
+        match n.to_u128() {
+            Some(n) => self.nth_back_u128(n),
+            None => {
+                self.done = true;
 
-            if self.next < self.back {
-                return Some(unsafe { self.next_back_unchecked() });
-            }
+                None
+            },
         }
+    }
 
-        self.next = self.size.clone();
+    /// Split off the upper half of the remaining addresses, seeking the new iterator to its first address.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        if self.done {
+            return None;
+        }
 
-        None
+        let distance = self.back - self.next;
+        let half = distance / 2 + (distance & 1);
+
+        if half == 0 {
+            return None;
+        }
+
+        let mut upper = self.clone();
+
+        upper.nth_u128(half - 1);
+
+        self.back = upper.next - 1;
+
+        Some(upper)
     }
 }
 
@@ -204,50 +328,50 @@ impl Iterator for Ipv6CidrU16ArrayIterator {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            Some(unsafe { self.next_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            Some(self.next_unchecked())
         }
     }
 
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            self.next = self.back.clone() - BigUint::one();
-
-            Some(unsafe { self.next_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            self.next = self.back;
+
+            Some(self.next_unchecked())
         }
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.nth_big_uint(BigUint::from(n))
+        self.nth_u128(n as u128)
     }
 }
 
 impl DoubleEndedIterator for Ipv6CidrU16ArrayIterator {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.next < self.back {
-            Some(unsafe { self.next_back_unchecked() })
-        } else {
+        if self.done {
             None
+        } else {
+            Some(self.next_back_unchecked())
         }
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.nth_back_big_uint(BigUint::from(n))
+        self.nth_back_u128(n as u128)
     }
 }
 
 // TODO: Ipv6CidrIterator
 
 /// To iterate IPv6 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv6CidrIterator {
     iter: Ipv6CidrU8ArrayIterator,
 }
@@ -262,15 +386,35 @@ impl Ipv6CidrIterator {
 }
 
 impl Ipv6CidrIterator {
+    #[inline]
+    pub fn nth_u128(&mut self, n: u128) -> Option<u128> {
+        self.iter.nth_u128(n).map(u128::from_be_bytes)
+    }
+
+    #[inline]
+    pub fn nth_back_u128(&mut self, n: u128) -> Option<u128> {
+        self.iter.nth_back_u128(n).map(u128::from_be_bytes)
+    }
+
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_big_uint(&mut self, n: BigUint) -> Option<u128> {
         self.iter.nth_big_uint(n).map(u128::from_be_bytes)
     }
 
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_back_big_uint(&mut self, n: BigUint) -> Option<u128> {
         self.iter.nth_back_big_uint(n).map(u128::from_be_bytes)
     }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        self.iter.split_off().map(|iter| Self {
+            iter,
+        })
+    }
 }
 
 impl Iterator for Ipv6CidrIterator {
@@ -307,7 +451,7 @@ impl DoubleEndedIterator for Ipv6CidrIterator {
 // TODO: Ipv6CidrIpv6AddrIterator
 
 /// To iterate IPv4 CIDRs.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ipv6CidrIpv6AddrIterator {
     iter: Ipv6CidrU16ArrayIterator,
 }
@@ -322,6 +466,19 @@ impl Ipv6CidrIpv6AddrIterator {
 }
 
 impl Ipv6CidrIpv6AddrIterator {
+    #[inline]
+    pub fn nth_u128(&mut self, n: u128) -> Option<Ipv6Addr> {
+        self.iter.nth_u128(n).map(|a| Ipv6Addr::new(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]))
+    }
+
+    #[inline]
+    pub fn nth_back_u128(&mut self, n: u128) -> Option<Ipv6Addr> {
+        self.iter
+            .nth_back_u128(n)
+            .map(|a| Ipv6Addr::new(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]))
+    }
+
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_big_uint(&mut self, n: BigUint) -> Option<Ipv6Addr> {
         self.iter
@@ -329,6 +486,7 @@ impl Ipv6CidrIpv6AddrIterator {
             .map(|a| Ipv6Addr::new(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]))
     }
 
+    #[cfg(feature = "bigint")]
     #[inline]
     pub fn nth_back_big_uint(&mut self, n: BigUint) -> Option<Ipv6Addr> {
         self.iter
@@ -336,11 +494,20 @@ impl Ipv6CidrIpv6AddrIterator {
             .map(|a| Ipv6Addr::new(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]))
     }
 
+    #[cfg(feature = "bigint")]
     #[deprecated(since = "2.7.0", note = "Use `nth_big_uint` instead")]
     #[inline]
     pub fn nth_back_big_int(&mut self, n: BigUint) -> Option<Ipv6Addr> {
         self.nth_back_big_uint(n)
     }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn split_off(&mut self) -> Option<Self> {
+        self.iter.split_off().map(|iter| Self {
+            iter,
+        })
+    }
 }
 
 impl Iterator for Ipv6CidrIpv6AddrIterator {
@@ -101,18 +101,100 @@ assert_eq!("192.168.56.128/26".to_string(), result[2].to_string());
 assert_eq!("192.168.56.192/26".to_string(), result[3].to_string());
 # }
 ```
+
+## Stepping Through Addresses
+
+The IPv4 CIDR iterators have a `step_by_u64` method and the IPv6 CIDR iterators have a `step_by_u128` method, which step by an amount as large as the address space and seek to each address instead of stepping through the skipped ones. `Iterator::step_by` is still available.
+
+```rust
+# #[cfg(feature = "iterator")]
+# {
+use std::str::FromStr;
+
+use cidr::Ipv6Cidr;
+use cidr_utils::iterator::Ipv6CidrIpv6AddrIterator;
+
+let cidr = Ipv6Cidr::from_str("2001:db8::/48").unwrap();
+
+// the first address of every /64
+let mut iter = Ipv6CidrIpv6AddrIterator::new(&cidr).step_by_u128(1 << 64);
+
+assert_eq!("2001:db8::", iter.next().unwrap().to_string());
+assert_eq!("2001:db8:0:1::", iter.next().unwrap().to_string());
+# }
+```
+
+## Parallel Iteration
+
+Enable the `rayon` feature to iterate addresses in parallel. The CIDR iterators implement `IntoParallelIterator`, and the combiners have a `par_addrs` method. Work is split by address offset, so a single large CIDR is also spread across threads.
+
+```rust
+# #[cfg(feature = "rayon")]
+# {
+use std::str::FromStr;
+
+use cidr::Ipv4Cidr;
+use cidr_utils::iterator::Ipv4CidrIpv4AddrIterator;
+use rayon::prelude::*;
+
+let cidr = Ipv4Cidr::from_str("10.0.0.0/16").unwrap();
+
+let count = Ipv4CidrIpv4AddrIterator::new(&cidr).into_par_iter().filter(|addr| addr.octets()[3] == 0).count();
+
+assert_eq!(256, count);
+# }
+```
+
+## No Std
+
+Disable the default features to compile this crate without std. The `iterator` feature and the size traits only need `core`, and the other features need `alloc`.
+
+```toml
+[dependencies.cidr-utils]
+version = "*"
+default-features = false
+features = ["combiner"]
+```
 */
 
+#![no_std]
 #![allow(unexpected_cfgs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "array-combiner")]
+pub mod array_combiner;
 #[cfg(feature = "combiner")]
 pub mod combiner;
+#[cfg(feature = "diff")]
+pub mod diff;
+#[cfg(feature = "dns")]
+pub mod dns;
+#[cfg(feature = "export")]
+pub mod export;
 #[cfg(feature = "iterator")]
 pub mod iterator;
+#[cfg(any(feature = "combiner", feature = "array-combiner"))]
+mod merge;
+#[cfg(feature = "overlap")]
+pub mod overlap;
+#[cfg(feature = "provenance")]
+pub mod provenance;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "sampler")]
+pub mod sampler;
 #[cfg(feature = "separator")]
 pub mod separator;
+#[cfg(feature = "table")]
+pub mod table;
 mod traits;
+#[cfg(feature = "translation")]
+pub mod translation;
+#[cfg(feature = "utilization")]
+pub mod utilization;
 
 pub extern crate cidr;
 
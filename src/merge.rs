@@ -0,0 +1,119 @@
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+
+use crate::{Ipv4CidrNavigation, Ipv6CidrNavigation};
+
+/// The operations on a CIDR needed by `place`.
+pub(crate) trait MergeableCidr: Copy + Ord {
+    /// Check whether this CIDR contains the first address of another one.
+    fn covers(&self, other: &Self) -> bool;
+
+    fn bits(&self) -> u8;
+
+    fn sibling(&self) -> Option<Self>;
+
+    fn parent(&self) -> Option<Self>;
+}
+
+impl MergeableCidr for Ipv4Cidr {
+    #[inline]
+    fn covers(&self, other: &Self) -> bool {
+        self.contains(&other.first_address())
+    }
+
+    #[inline]
+    fn bits(&self) -> u8 {
+        self.network_length()
+    }
+
+    #[inline]
+    fn sibling(&self) -> Option<Self> {
+        Ipv4CidrNavigation::sibling(self)
+    }
+
+    #[inline]
+    fn parent(&self) -> Option<Self> {
+        Ipv4CidrNavigation::parent(self)
+    }
+}
+
+impl MergeableCidr for Ipv6Cidr {
+    #[inline]
+    fn covers(&self, other: &Self) -> bool {
+        self.contains(&other.first_address())
+    }
+
+    #[inline]
+    fn bits(&self) -> u8 {
+        self.network_length()
+    }
+
+    #[inline]
+    fn sibling(&self) -> Option<Self> {
+        Ipv6CidrNavigation::sibling(self)
+    }
+
+    #[inline]
+    fn parent(&self) -> Option<Self> {
+        Ipv6CidrNavigation::parent(self)
+    }
+}
+
+/// Where a CIDR goes when it is pushed into sorted, disjoint and merged entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Placement<C> {
+    /// The CIDR is already covered by the entry at this index.
+    Covered(usize),
+    /// The entries in `start..end` are replaced by `cidr`, which is the CIDR merged with the entries it covers and its siblings.
+    Replace { start: usize, end: usize, cidr: C },
+}
+
+/// Find where a CIDR goes in sorted, disjoint and merged entries. Siblings whose network length is `bits` are merged only if `can_merge(bits)`.
+pub(crate) fn place<C: MergeableCidr>(
+    entries: &[C],
+    cidr: C,
+    can_merge: impl Fn(u8) -> bool,
+) -> Placement<C> {
+    let mut start = match entries.binary_search(&cidr) {
+        Ok(index) => return Placement::Covered(index),
+        Err(index) => index,
+    };
+
+    if start > 0 && entries[start - 1].covers(&cidr) {
+        return Placement::Covered(start - 1);
+    }
+
+    let mut end = start;
+
+    while end < entries.len() && cidr.covers(&entries[end]) {
+        end += 1;
+    }
+
+    let mut cidr = cidr;
+    let mut merging = true;
+
+    while merging {
+        merging = false;
+
+        if end < entries.len() && can_merge(cidr.bits()) && cidr.sibling() == Some(entries[end]) {
+            cidr = cidr.parent().unwrap();
+
+            end += 1;
+
+            merging = true;
+        }
+
+        if start > 0 && can_merge(cidr.bits()) && cidr.sibling() == Some(entries[start - 1]) {
+            cidr = cidr.parent().unwrap();
+
+            start -= 1;
+
+            merging = true;
+        }
+    }
+
+    Placement::Replace {
+        start,
+        end,
+        cidr,
+    }
+}
@@ -0,0 +1,7 @@
+//! Find the overlapping address space between labeled CIDR lists, e.g. the ranges of multiple VPCs.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
@@ -0,0 +1,123 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use cidr::Ipv4Cidr;
+
+use crate::combiner::Ipv4CidrCombiner;
+
+/// The overlapping address space between the CIDRs of two labels.
+#[derive(Debug, Clone)]
+pub struct Ipv4Overlap<'a, L> {
+    pub labels: (&'a L, &'a L),
+    pub cidrs:  Ipv4CidrCombiner,
+}
+
+/// To find every pair of labels whose CIDRs overlap.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{
+///     combiner::Ipv4CidrCombiner, overlap::Ipv4OverlapAnalyzer,
+/// };
+///
+/// let mut analyzer = Ipv4OverlapAnalyzer::new();
+///
+/// let mut vpc_a = Ipv4CidrCombiner::new();
+/// vpc_a.push(Ipv4Cidr::from_str("10.0.0.0/16").unwrap());
+///
+/// let mut vpc_b = Ipv4CidrCombiner::new();
+/// vpc_b.push(Ipv4Cidr::from_str("10.0.128.0/20").unwrap());
+/// vpc_b.push(Ipv4Cidr::from_str("10.1.0.0/16").unwrap());
+///
+/// analyzer.insert("vpc-a", vpc_a);
+/// analyzer.insert("vpc-b", vpc_b);
+///
+/// let overlaps = analyzer.analyze();
+///
+/// assert_eq!(1, overlaps.len());
+/// assert_eq!((&"vpc-a", &"vpc-b"), overlaps[0].labels);
+/// assert_eq!("[10.0.128.0/20]", overlaps[0].cidrs.to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ipv4OverlapAnalyzer<L> {
+    inputs: Vec<(L, Ipv4CidrCombiner)>,
+}
+
+impl<L> Default for Ipv4OverlapAnalyzer<L> {
+    #[inline]
+    fn default() -> Self {
+        Ipv4OverlapAnalyzer::new()
+    }
+}
+
+impl<L> Ipv4OverlapAnalyzer<L> {
+    /// Create a new `Ipv4OverlapAnalyzer` instance.
+    #[inline]
+    pub const fn new() -> Ipv4OverlapAnalyzer<L> {
+        Ipv4OverlapAnalyzer {
+            inputs: Vec::new()
+        }
+    }
+
+    /// Add the CIDRs of a label. Labels are not compared with each other, so inserting the same label twice adds two separate inputs.
+    #[inline]
+    pub fn insert(&mut self, label: L, cidrs: Ipv4CidrCombiner) {
+        self.inputs.push((label, cidrs));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+}
+
+impl<L> Ipv4OverlapAnalyzer<L> {
+    /// Find every pair of labels whose CIDRs overlap, with the exact overlapping CIDRs. The pairs are ordered by the insertion order of their labels.
+    pub fn analyze(&self) -> Vec<Ipv4Overlap<'_, L>> {
+        let mut entries: Vec<(Ipv4Cidr, usize)> = self
+            .inputs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (_, cidrs))| cidrs.iter().map(move |cidr| (*cidr, index)))
+            .collect();
+
+        entries.sort_unstable();
+
+        let mut overlaps: BTreeMap<(usize, usize), Ipv4CidrCombiner> = BTreeMap::new();
+
+        // two CIDRs either nest or are disjoint, so the entries containing the current one form a stack
+        let mut stack: Vec<(Ipv4Cidr, usize)> = Vec::new();
+
+        for (cidr, index) in entries {
+            while stack.last().is_some_and(|(c, _)| c.last_address() < cidr.first_address()) {
+                stack.pop();
+            }
+
+            for &(_, outer_index) in stack.iter() {
+                if outer_index != index {
+                    let key = (outer_index.min(index), outer_index.max(index));
+
+                    overlaps.entry(key).or_default().push(cidr);
+                }
+            }
+
+            stack.push((cidr, index));
+        }
+
+        overlaps
+            .into_iter()
+            .map(|((a, b), cidrs)| Ipv4Overlap {
+                labels: (&self.inputs[a].0, &self.inputs[b].0),
+                cidrs,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,94 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use cidr::Ipv6Cidr;
+
+use crate::combiner::Ipv6CidrCombiner;
+
+/// The overlapping address space between the CIDRs of two labels.
+#[derive(Debug, Clone)]
+pub struct Ipv6Overlap<'a, L> {
+    pub labels: (&'a L, &'a L),
+    pub cidrs:  Ipv6CidrCombiner,
+}
+
+/// To find every pair of labels whose CIDRs overlap.
+#[derive(Debug, Clone)]
+pub struct Ipv6OverlapAnalyzer<L> {
+    inputs: Vec<(L, Ipv6CidrCombiner)>,
+}
+
+impl<L> Default for Ipv6OverlapAnalyzer<L> {
+    #[inline]
+    fn default() -> Self {
+        Ipv6OverlapAnalyzer::new()
+    }
+}
+
+impl<L> Ipv6OverlapAnalyzer<L> {
+    /// Create a new `Ipv6OverlapAnalyzer` instance.
+    #[inline]
+    pub const fn new() -> Ipv6OverlapAnalyzer<L> {
+        Ipv6OverlapAnalyzer {
+            inputs: Vec::new()
+        }
+    }
+
+    /// Add the CIDRs of a label. Labels are not compared with each other, so inserting the same label twice adds two separate inputs.
+    #[inline]
+    pub fn insert(&mut self, label: L, cidrs: Ipv6CidrCombiner) {
+        self.inputs.push((label, cidrs));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+}
+
+impl<L> Ipv6OverlapAnalyzer<L> {
+    /// Find every pair of labels whose CIDRs overlap, with the exact overlapping CIDRs. The pairs are ordered by the insertion order of their labels.
+    pub fn analyze(&self) -> Vec<Ipv6Overlap<'_, L>> {
+        let mut entries: Vec<(Ipv6Cidr, usize)> = self
+            .inputs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (_, cidrs))| cidrs.iter().map(move |cidr| (*cidr, index)))
+            .collect();
+
+        entries.sort_unstable();
+
+        let mut overlaps: BTreeMap<(usize, usize), Ipv6CidrCombiner> = BTreeMap::new();
+
+        // two CIDRs either nest or are disjoint, so the entries containing the current one form a stack
+        let mut stack: Vec<(Ipv6Cidr, usize)> = Vec::new();
+
+        for (cidr, index) in entries {
+            while stack.last().is_some_and(|(c, _)| c.last_address() < cidr.first_address()) {
+                stack.pop();
+            }
+
+            for &(_, outer_index) in stack.iter() {
+                if outer_index != index {
+                    let key = (outer_index.min(index), outer_index.max(index));
+
+                    overlaps.entry(key).or_default().push(cidr);
+                }
+            }
+
+            stack.push((cidr, index));
+        }
+
+        overlaps
+            .into_iter()
+            .map(|((a, b), cidrs)| Ipv6Overlap {
+                labels: (&self.inputs[a].0, &self.inputs[b].0),
+                cidrs,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,7 @@
+//! Combine CIDRs to supernetworks while recording which inputs contributed to each combined entry.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
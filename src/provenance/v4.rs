@@ -0,0 +1,160 @@
+use alloc::vec::Vec;
+use core::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+
+use crate::{
+    combiner::{Ipv4CidrCombiner, MergePolicy},
+    merge::{place, Placement},
+};
+
+/// To combine multiple IPv4 CIDRs to supernetworks like `Ipv4CidrCombiner`, keeping the tags of the inputs which contributed to each combined entry.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv4Addr, str::FromStr};
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::provenance::Ipv4ProvenanceCombiner;
+///
+/// let mut combiner = Ipv4ProvenanceCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.0/25").unwrap(), "office");
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.128/25").unwrap(), "lab");
+/// combiner.push(Ipv4Cidr::from_str("10.0.0.7").unwrap(), "printer");
+///
+/// let (cidr, tags) = combiner.find(&Ipv4Addr::new(10, 0, 0, 200)).unwrap();
+///
+/// assert_eq!(Ipv4Cidr::from_str("10.0.0.0/24").unwrap(), *cidr);
+/// assert_eq!(&["office", "lab", "printer"], tags);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ipv4ProvenanceCombiner<T> {
+    cidrs:  Vec<Ipv4Cidr>,
+    tags:   Vec<Vec<T>>,
+    policy: MergePolicy,
+}
+
+impl<T> Default for Ipv4ProvenanceCombiner<T> {
+    #[inline]
+    fn default() -> Self {
+        Ipv4ProvenanceCombiner::new()
+    }
+}
+
+impl<T> Ipv4ProvenanceCombiner<T> {
+    /// Create a new `Ipv4ProvenanceCombiner` instance.
+    #[inline]
+    pub const fn new() -> Ipv4ProvenanceCombiner<T> {
+        Ipv4ProvenanceCombiner::with_policy(MergePolicy::new())
+    }
+
+    /// Create a new `Ipv4ProvenanceCombiner` instance with a specific merge policy.
+    #[inline]
+    pub const fn with_policy(policy: MergePolicy) -> Ipv4ProvenanceCombiner<T> {
+        Ipv4ProvenanceCombiner {
+            cidrs: Vec::new(),
+            tags: Vec::new(),
+            policy,
+        }
+    }
+
+    /// Get the merge policy of this combiner.
+    #[inline]
+    pub const fn policy(&self) -> MergePolicy {
+        self.policy
+    }
+
+    /// Get the combined CIDRs.
+    #[inline]
+    pub fn cidrs(&self) -> &[Ipv4Cidr] {
+        &self.cidrs
+    }
+
+    /// Get the tags of the inputs which contributed to the combined CIDR at an index.
+    #[inline]
+    pub fn tags(&self, index: usize) -> Option<&[T]> {
+        self.tags.get(index).map(Vec::as_slice)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cidrs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cidrs.is_empty()
+    }
+
+    /// Iterate the combined CIDRs with the tags of their inputs.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Ipv4Cidr, &[T])> {
+        self.cidrs.iter().zip(self.tags.iter().map(Vec::as_slice))
+    }
+
+    /// Get a plain combiner with the same CIDRs.
+    #[inline]
+    pub fn to_combiner(&self) -> Ipv4CidrCombiner {
+        let mut combiner = Ipv4CidrCombiner::with_policy(self.policy);
+
+        for cidr in self.cidrs.iter() {
+            combiner.push(*cidr);
+        }
+
+        combiner
+    }
+}
+
+impl<T> Ipv4ProvenanceCombiner<T> {
+    /// Push a CIDR with a tag (e.g. the index of the input) into this combiner. If the CIDR is already covered, the tag is added to the covering entry.
+    pub fn push(&mut self, cidr: Ipv4Cidr, tag: T) {
+        match place(&self.cidrs, cidr, |bits| self.policy.can_merge(bits)) {
+            Placement::Covered(index) => self.tags[index].push(tag),
+            Placement::Replace {
+                start,
+                end,
+                cidr,
+            } => {
+                let mut tags: Vec<T> = self.tags.drain(start..end).flatten().collect();
+
+                tags.push(tag);
+
+                self.cidrs.splice(start..end, [cidr]);
+                self.tags.insert(start, tags);
+            },
+        }
+    }
+
+    /// Find the combined CIDR which contains an IPv4 address, with the tags of its inputs.
+    #[inline]
+    pub fn find(&self, ipv4: &Ipv4Addr) -> Option<(&Ipv4Cidr, &[T])> {
+        let index = self.cidrs.partition_point(|cidr| cidr.first_address() <= *ipv4);
+
+        if index > 0 && self.cidrs[index - 1].contains(ipv4) {
+            Some((&self.cidrs[index - 1], &self.tags[index - 1]))
+        } else {
+            None
+        }
+    }
+
+    /// Check an IPv4 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv4: &Ipv4Addr) -> bool {
+        self.find(ipv4).is_some()
+    }
+}
+
+impl<T> FromIterator<(Ipv4Cidr, T)> for Ipv4ProvenanceCombiner<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Ipv4Cidr, T)>>(iter: I) -> Self {
+        let mut combiner = Ipv4ProvenanceCombiner::new();
+
+        for (cidr, tag) in iter {
+            combiner.push(cidr, tag);
+        }
+
+        combiner
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+
+use crate::{
+    combiner::{Ipv6CidrCombiner, MergePolicy},
+    merge::{place, Placement},
+};
+
+/// To combine multiple IPv6 CIDRs to supernetworks like `Ipv6CidrCombiner`, keeping the tags of the inputs which contributed to each combined entry.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv6Addr, str::FromStr};
+///
+/// use cidr::Ipv6Cidr;
+/// use cidr_utils::provenance::Ipv6ProvenanceCombiner;
+///
+/// let mut combiner = Ipv6ProvenanceCombiner::new();
+///
+/// combiner.push(Ipv6Cidr::from_str("2001:db8::/33").unwrap(), "office");
+/// combiner.push(Ipv6Cidr::from_str("2001:db8:8000::/33").unwrap(), "lab");
+/// combiner.push(Ipv6Cidr::from_str("2001:db8::7").unwrap(), "printer");
+///
+/// let (cidr, tags) = combiner
+///     .find(&Ipv6Addr::from_str("2001:db8:ffff::1").unwrap())
+///     .unwrap();
+///
+/// assert_eq!(Ipv6Cidr::from_str("2001:db8::/32").unwrap(), *cidr);
+/// assert_eq!(&["office", "lab", "printer"], tags);
+/// ```
+
+#[derive(Debug, Clone)]
+pub struct Ipv6ProvenanceCombiner<T> {
+    cidrs:  Vec<Ipv6Cidr>,
+    tags:   Vec<Vec<T>>,
+    policy: MergePolicy,
+}
+
+impl<T> Default for Ipv6ProvenanceCombiner<T> {
+    #[inline]
+    fn default() -> Self {
+        Ipv6ProvenanceCombiner::new()
+    }
+}
+
+impl<T> Ipv6ProvenanceCombiner<T> {
+    /// Create a new `Ipv6ProvenanceCombiner` instance.
+    #[inline]
+    pub const fn new() -> Ipv6ProvenanceCombiner<T> {
+        Ipv6ProvenanceCombiner::with_policy(MergePolicy::new())
+    }
+
+    /// Create a new `Ipv6ProvenanceCombiner` instance with a specific merge policy.
+    #[inline]
+    pub const fn with_policy(policy: MergePolicy) -> Ipv6ProvenanceCombiner<T> {
+        Ipv6ProvenanceCombiner {
+            cidrs: Vec::new(),
+            tags: Vec::new(),
+            policy,
+        }
+    }
+
+    /// Get the merge policy of this combiner.
+    #[inline]
+    pub const fn policy(&self) -> MergePolicy {
+        self.policy
+    }
+
+    /// Get the combined CIDRs.
+    #[inline]
+    pub fn cidrs(&self) -> &[Ipv6Cidr] {
+        &self.cidrs
+    }
+
+    /// Get the tags of the inputs which contributed to the combined CIDR at an index.
+    #[inline]
+    pub fn tags(&self, index: usize) -> Option<&[T]> {
+        self.tags.get(index).map(Vec::as_slice)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cidrs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cidrs.is_empty()
+    }
+
+    /// Iterate the combined CIDRs with the tags of their inputs.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Ipv6Cidr, &[T])> {
+        self.cidrs.iter().zip(self.tags.iter().map(Vec::as_slice))
+    }
+
+    /// Get a plain combiner with the same CIDRs.
+    #[inline]
+    pub fn to_combiner(&self) -> Ipv6CidrCombiner {
+        let mut combiner = Ipv6CidrCombiner::with_policy(self.policy);
+
+        for cidr in self.cidrs.iter() {
+            combiner.push(*cidr);
+        }
+
+        combiner
+    }
+}
+
+impl<T> Ipv6ProvenanceCombiner<T> {
+    /// Push a CIDR with a tag (e.g. the index of the input) into this combiner. If the CIDR is already covered, the tag is added to the covering entry.
+    pub fn push(&mut self, cidr: Ipv6Cidr, tag: T) {
+        match place(&self.cidrs, cidr, |bits| self.policy.can_merge(bits)) {
+            Placement::Covered(index) => self.tags[index].push(tag),
+            Placement::Replace {
+                start,
+                end,
+                cidr,
+            } => {
+                let mut tags: Vec<T> = self.tags.drain(start..end).flatten().collect();
+
+                tags.push(tag);
+
+                self.cidrs.splice(start..end, [cidr]);
+                self.tags.insert(start, tags);
+            },
+        }
+    }
+
+    /// Find the combined CIDR which contains an IPv6 address, with the tags of its inputs.
+    #[inline]
+    pub fn find(&self, ipv6: &Ipv6Addr) -> Option<(&Ipv6Cidr, &[T])> {
+        let index = self.cidrs.partition_point(|cidr| cidr.first_address() <= *ipv6);
+
+        if index > 0 && self.cidrs[index - 1].contains(ipv6) {
+            Some((&self.cidrs[index - 1], &self.tags[index - 1]))
+        } else {
+            None
+        }
+    }
+
+    /// Check an IPv6 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv6: &Ipv6Addr) -> bool {
+        self.find(ipv6).is_some()
+    }
+}
+
+impl<T> FromIterator<(Ipv6Cidr, T)> for Ipv6ProvenanceCombiner<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Ipv6Cidr, T)>>(iter: I) -> Self {
+        let mut combiner = Ipv6ProvenanceCombiner::new();
+
+        for (cidr, tag) in iter {
+            combiner.push(cidr, tag);
+        }
+
+        combiner
+    }
+}
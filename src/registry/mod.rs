@@ -0,0 +1,95 @@
+//! Classify addresses against the IANA special-purpose address registries ([RFC 6890](https://www.rfc-editor.org/rfc/rfc6890) and its successors).
+//!
+//! Multicast ranges are not part of the special-purpose registries, but they are also included here.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
+
+/// The kinds of special-purpose address blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialPurposeKind {
+    /// "This network" and "this host on this network".
+    ThisNetwork,
+    /// The unspecified IPv6 address.
+    Unspecified,
+    /// Private-use networks ([RFC 1918](https://www.rfc-editor.org/rfc/rfc1918)).
+    PrivateUse,
+    /// Unique local IPv6 unicast addresses ([RFC 4193](https://www.rfc-editor.org/rfc/rfc4193)).
+    UniqueLocal,
+    /// The shared address space used by carrier-grade NATs ([RFC 6598](https://www.rfc-editor.org/rfc/rfc6598)).
+    SharedAddressSpace,
+    Loopback,
+    LinkLocal,
+    Documentation,
+    Benchmarking,
+    Multicast,
+    /// The limited broadcast address.
+    Broadcast,
+    /// Reserved for future use.
+    Reserved,
+    /// IPv4-mapped addresses and IPv4/IPv6 translation prefixes.
+    Translation,
+    /// 6to4 and Teredo.
+    Tunneling,
+    /// The discard-only IPv6 prefix ([RFC 6666](https://www.rfc-editor.org/rfc/rfc6666)).
+    Discard,
+    /// Protocol assignments, anycast addresses and other special-purpose blocks.
+    Other,
+}
+
+/// The attributes of an entry in the IANA special-purpose address registries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecialPurpose {
+    /// The name of the address block in the registry.
+    pub name:                 &'static str,
+    /// The RFC which defines the address block.
+    pub rfc:                  &'static str,
+    pub kind:                 SpecialPurposeKind,
+    /// Whether an address from the block is valid as a source address.
+    pub source:               bool,
+    /// Whether an address from the block is valid as a destination address.
+    pub destination:          bool,
+    /// Whether a router may forward packets with an address from the block.
+    pub forwardable:          bool,
+    /// Whether an address from the block is globally reachable. Blocks which the registry marks as "N/A" are treated as not globally reachable.
+    pub globally_reachable:   bool,
+    /// Whether the block is reserved by a protocol and cannot be reassigned.
+    pub reserved_by_protocol: bool,
+}
+
+impl SpecialPurpose {
+    /// Check whether the block is for private networks, including IPv6 unique local addresses.
+    #[inline]
+    pub const fn is_private(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::PrivateUse | SpecialPurposeKind::UniqueLocal)
+    }
+
+    #[inline]
+    pub const fn is_loopback(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::Loopback)
+    }
+
+    #[inline]
+    pub const fn is_documentation(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::Documentation)
+    }
+
+    /// Check whether the block is the shared address space used by carrier-grade NATs.
+    #[inline]
+    pub const fn is_cgnat(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::SharedAddressSpace)
+    }
+
+    #[inline]
+    pub const fn is_benchmarking(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::Benchmarking)
+    }
+
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        matches!(self.kind, SpecialPurposeKind::Multicast)
+    }
+}
@@ -0,0 +1,489 @@
+use core::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+
+use super::{SpecialPurpose, SpecialPurposeKind};
+use crate::combiner::Ipv4CidrCombiner;
+
+/// An entry in the IANA IPv4 special-purpose address registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ipv4SpecialPurposeEntry {
+    address:        Ipv4Addr,
+    network_length: u8,
+    purpose:        SpecialPurpose,
+}
+
+impl Ipv4SpecialPurposeEntry {
+    /// Get the address block of this entry.
+    #[inline]
+    pub fn cidr(&self) -> Ipv4Cidr {
+        Ipv4Cidr::new(self.address, self.network_length).unwrap()
+    }
+
+    #[inline]
+    pub const fn purpose(&self) -> &SpecialPurpose {
+        &self.purpose
+    }
+
+    #[inline]
+    fn contains(&self, ipv4: &Ipv4Addr) -> bool {
+        let mask = u32::MAX.checked_shl(32 - self.network_length as u32).unwrap_or(0);
+
+        ipv4.to_bits() & mask == self.address.to_bits()
+    }
+}
+
+static ENTRIES: [Ipv4SpecialPurposeEntry; 27] = [
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(0, 0, 0, 0),
+        network_length: 8,
+        purpose:        SpecialPurpose {
+            name:                 "This network",
+            rfc:                  "RFC 791",
+            kind:                 SpecialPurposeKind::ThisNetwork,
+            source:               true,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(0, 0, 0, 0),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "This host on this network",
+            rfc:                  "RFC 1122",
+            kind:                 SpecialPurposeKind::ThisNetwork,
+            source:               true,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(10, 0, 0, 0),
+        network_length: 8,
+        purpose:        SpecialPurpose {
+            name:                 "Private-Use",
+            rfc:                  "RFC 1918",
+            kind:                 SpecialPurposeKind::PrivateUse,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(100, 64, 0, 0),
+        network_length: 10,
+        purpose:        SpecialPurpose {
+            name:                 "Shared Address Space",
+            rfc:                  "RFC 6598",
+            kind:                 SpecialPurposeKind::SharedAddressSpace,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(127, 0, 0, 0),
+        network_length: 8,
+        purpose:        SpecialPurpose {
+            name:                 "Loopback",
+            rfc:                  "RFC 1122",
+            kind:                 SpecialPurposeKind::Loopback,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(169, 254, 0, 0),
+        network_length: 16,
+        purpose:        SpecialPurpose {
+            name:                 "Link Local",
+            rfc:                  "RFC 3927",
+            kind:                 SpecialPurposeKind::LinkLocal,
+            source:               true,
+            destination:          true,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(172, 16, 0, 0),
+        network_length: 12,
+        purpose:        SpecialPurpose {
+            name:                 "Private-Use",
+            rfc:                  "RFC 1918",
+            kind:                 SpecialPurposeKind::PrivateUse,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "IETF Protocol Assignments",
+            rfc:                  "RFC 6890",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 0),
+        network_length: 29,
+        purpose:        SpecialPurpose {
+            name:                 "IPv4 Service Continuity Prefix",
+            rfc:                  "RFC 7335",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 8),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "IPv4 dummy address",
+            rfc:                  "RFC 7600",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 9),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "Port Control Protocol Anycast",
+            rfc:                  "RFC 7723",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 10),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "Traversal Using Relays around NAT Anycast",
+            rfc:                  "RFC 8155",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 170),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "NAT64/DNS64 Discovery",
+            rfc:                  "RFC 8880",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 0, 171),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "NAT64/DNS64 Discovery",
+            rfc:                  "RFC 8880",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 0, 2, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "Documentation (TEST-NET-1)",
+            rfc:                  "RFC 5737",
+            kind:                 SpecialPurposeKind::Documentation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 31, 196, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "AS112-v4",
+            rfc:                  "RFC 7535",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 52, 193, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "AMT",
+            rfc:                  "RFC 7450",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 88, 99, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "Deprecated (6to4 Relay Anycast)",
+            rfc:                  "RFC 7526",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 88, 99, 2),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "6a44-relay anycast address",
+            rfc:                  "RFC 6751",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 168, 0, 0),
+        network_length: 16,
+        purpose:        SpecialPurpose {
+            name:                 "Private-Use",
+            rfc:                  "RFC 1918",
+            kind:                 SpecialPurposeKind::PrivateUse,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(192, 175, 48, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "Direct Delegation AS112 Service",
+            rfc:                  "RFC 7534",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(198, 18, 0, 0),
+        network_length: 15,
+        purpose:        SpecialPurpose {
+            name:                 "Benchmarking",
+            rfc:                  "RFC 2544",
+            kind:                 SpecialPurposeKind::Benchmarking,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(198, 51, 100, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "Documentation (TEST-NET-2)",
+            rfc:                  "RFC 5737",
+            kind:                 SpecialPurposeKind::Documentation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(203, 0, 113, 0),
+        network_length: 24,
+        purpose:        SpecialPurpose {
+            name:                 "Documentation (TEST-NET-3)",
+            rfc:                  "RFC 5737",
+            kind:                 SpecialPurposeKind::Documentation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(224, 0, 0, 0),
+        network_length: 4,
+        purpose:        SpecialPurpose {
+            name:                 "Multicast",
+            rfc:                  "RFC 5771",
+            kind:                 SpecialPurposeKind::Multicast,
+            source:               false,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(240, 0, 0, 0),
+        network_length: 4,
+        purpose:        SpecialPurpose {
+            name:                 "Reserved",
+            rfc:                  "RFC 1112",
+            kind:                 SpecialPurposeKind::Reserved,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv4SpecialPurposeEntry {
+        address:        Ipv4Addr::new(255, 255, 255, 255),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "Limited Broadcast",
+            rfc:                  "RFC 919",
+            kind:                 SpecialPurposeKind::Broadcast,
+            source:               false,
+            destination:          true,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+];
+
+/// To classify IPv4 addresses against the IANA IPv4 special-purpose address registry.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv4Addr, str::FromStr};
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::registry::Ipv4SpecialPurposeRegistry;
+///
+/// let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(
+///     100, 64, 1, 1,
+/// ))
+/// .unwrap();
+///
+/// assert!(entry.purpose().is_cgnat());
+/// assert_eq!(Ipv4Cidr::from_str("100.64.0.0/10").unwrap(), entry.cidr());
+///
+/// assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(
+///     8, 8, 8, 8
+/// ))
+/// .is_none());
+/// ```
+#[derive(Debug)]
+pub struct Ipv4SpecialPurposeRegistry;
+
+impl Ipv4SpecialPurposeRegistry {
+    /// Get all entries, ordered by their address blocks.
+    #[inline]
+    pub fn entries() -> &'static [Ipv4SpecialPurposeEntry] {
+        &ENTRIES
+    }
+
+    /// Find the most specific entry whose address block contains an IPv4 address.
+    #[inline]
+    pub fn classify_addr(ipv4: &Ipv4Addr) -> Option<&'static Ipv4SpecialPurposeEntry> {
+        ENTRIES.iter().filter(|entry| entry.contains(ipv4)).max_by_key(|entry| entry.network_length)
+    }
+
+    /// Find the most specific entry whose address block contains a whole IPv4 CIDR.
+    #[inline]
+    pub fn classify_cidr(cidr: &Ipv4Cidr) -> Option<&'static Ipv4SpecialPurposeEntry> {
+        ENTRIES
+            .iter()
+            .filter(|entry| {
+                entry.network_length <= cidr.network_length()
+                    && entry.contains(&cidr.first_address())
+            })
+            .max_by_key(|entry| entry.network_length)
+    }
+
+    /// Get a combiner of the bogon address blocks, which are the address blocks not globally reachable. Globally reachable blocks nested in them (e.g. `192.0.0.9/32`) are not excluded.
+    pub fn bogons() -> Ipv4CidrCombiner {
+        let mut combiner = Ipv4CidrCombiner::new();
+
+        for entry in ENTRIES.iter().filter(|entry| !entry.purpose.globally_reachable) {
+            combiner.push(entry.cidr());
+        }
+
+        combiner
+    }
+
+    /// Get a combiner of the addresses which are not globally reachable. Unlike `bogons`, globally reachable blocks nested in the other blocks are excluded.
+    pub fn non_global() -> Ipv4CidrCombiner {
+        let mut combiner = Self::bogons();
+
+        for entry in ENTRIES.iter().filter(|entry| entry.purpose.globally_reachable) {
+            combiner.subtract(&entry.cidr());
+        }
+
+        combiner
+    }
+}
@@ -0,0 +1,461 @@
+use core::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+
+use super::{SpecialPurpose, SpecialPurposeKind};
+use crate::combiner::Ipv6CidrCombiner;
+
+/// An entry in the IANA IPv6 special-purpose address registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ipv6SpecialPurposeEntry {
+    address:        Ipv6Addr,
+    network_length: u8,
+    purpose:        SpecialPurpose,
+}
+
+impl Ipv6SpecialPurposeEntry {
+    /// Get the address block of this entry.
+    #[inline]
+    pub fn cidr(&self) -> Ipv6Cidr {
+        Ipv6Cidr::new(self.address, self.network_length).unwrap()
+    }
+
+    #[inline]
+    pub const fn purpose(&self) -> &SpecialPurpose {
+        &self.purpose
+    }
+
+    #[inline]
+    fn contains(&self, ipv6: &Ipv6Addr) -> bool {
+        let mask = u128::MAX.checked_shl(128 - self.network_length as u32).unwrap_or(0);
+
+        ipv6.to_bits() & mask == self.address.to_bits()
+    }
+}
+
+static ENTRIES: [Ipv6SpecialPurposeEntry; 25] = [
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 128,
+        purpose:        SpecialPurpose {
+            name:                 "Unspecified Address",
+            rfc:                  "RFC 4291",
+            kind:                 SpecialPurposeKind::Unspecified,
+            source:               true,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1),
+        network_length: 128,
+        purpose:        SpecialPurpose {
+            name:                 "Loopback Address",
+            rfc:                  "RFC 4291",
+            kind:                 SpecialPurposeKind::Loopback,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0, 0, 0, 0, 0, 0xFFFF, 0, 0),
+        network_length: 96,
+        purpose:        SpecialPurpose {
+            name:                 "IPv4-mapped Address",
+            rfc:                  "RFC 4291",
+            kind:                 SpecialPurposeKind::Translation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x64, 0xFF9B, 0, 0, 0, 0, 0, 0),
+        network_length: 96,
+        purpose:        SpecialPurpose {
+            name:                 "IPv4-IPv6 Translat.",
+            rfc:                  "RFC 6052",
+            kind:                 SpecialPurposeKind::Translation,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x64, 0xFF9B, 1, 0, 0, 0, 0, 0),
+        network_length: 48,
+        purpose:        SpecialPurpose {
+            name:                 "IPv4-IPv6 Translat.",
+            rfc:                  "RFC 8215",
+            kind:                 SpecialPurposeKind::Translation,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 64,
+        purpose:        SpecialPurpose {
+            name:                 "Discard-Only Address Block",
+            rfc:                  "RFC 6666",
+            kind:                 SpecialPurposeKind::Discard,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 23,
+        purpose:        SpecialPurpose {
+            name:                 "IETF Protocol Assignments",
+            rfc:                  "RFC 2928",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "TEREDO",
+            rfc:                  "RFC 4380",
+            kind:                 SpecialPurposeKind::Tunneling,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 1),
+        network_length: 128,
+        purpose:        SpecialPurpose {
+            name:                 "Port Control Protocol Anycast",
+            rfc:                  "RFC 7723",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 2),
+        network_length: 128,
+        purpose:        SpecialPurpose {
+            name:                 "Traversal Using Relays around NAT Anycast",
+            rfc:                  "RFC 8155",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 3),
+        network_length: 128,
+        purpose:        SpecialPurpose {
+            name:                 "DNS-SD Service Registration Protocol Anycast",
+            rfc:                  "RFC 9665",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 2, 0, 0, 0, 0, 0, 0),
+        network_length: 48,
+        purpose:        SpecialPurpose {
+            name:                 "Benchmarking",
+            rfc:                  "RFC 5180",
+            kind:                 SpecialPurposeKind::Benchmarking,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 3, 0, 0, 0, 0, 0, 0),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "AMT",
+            rfc:                  "RFC 7450",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 4, 0x112, 0, 0, 0, 0, 0),
+        network_length: 48,
+        purpose:        SpecialPurpose {
+            name:                 "AS112-v6",
+            rfc:                  "RFC 7535",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0x10, 0, 0, 0, 0, 0, 0),
+        network_length: 28,
+        purpose:        SpecialPurpose {
+            name:                 "Deprecated (previously ORCHID)",
+            rfc:                  "RFC 4843",
+            kind:                 SpecialPurposeKind::Other,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0x20, 0, 0, 0, 0, 0, 0),
+        network_length: 28,
+        purpose:        SpecialPurpose {
+            name:                 "ORCHIDv2",
+            rfc:                  "RFC 7343",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0x30, 0, 0, 0, 0, 0, 0),
+        network_length: 28,
+        purpose:        SpecialPurpose {
+            name:                 "Drone Remote ID Protocol Entity Tags (DETs) Prefix",
+            rfc:                  "RFC 9374",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0),
+        network_length: 32,
+        purpose:        SpecialPurpose {
+            name:                 "Documentation",
+            rfc:                  "RFC 3849",
+            kind:                 SpecialPurposeKind::Documentation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 16,
+        purpose:        SpecialPurpose {
+            name:                 "6to4",
+            rfc:                  "RFC 3056",
+            kind:                 SpecialPurposeKind::Tunneling,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x2620, 0x4F, 0x8000, 0, 0, 0, 0, 0),
+        network_length: 48,
+        purpose:        SpecialPurpose {
+            name:                 "Direct Delegation AS112 Service",
+            rfc:                  "RFC 7534",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   true,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x3FFF, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 20,
+        purpose:        SpecialPurpose {
+            name:                 "Documentation",
+            rfc:                  "RFC 9637",
+            kind:                 SpecialPurposeKind::Documentation,
+            source:               false,
+            destination:          false,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0x5F00, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 16,
+        purpose:        SpecialPurpose {
+            name:                 "Segment Routing (SRv6) SIDs",
+            rfc:                  "RFC 9602",
+            kind:                 SpecialPurposeKind::Other,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0xFC00, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 7,
+        purpose:        SpecialPurpose {
+            name:                 "Unique-Local",
+            rfc:                  "RFC 4193",
+            kind:                 SpecialPurposeKind::UniqueLocal,
+            source:               true,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0xFE80, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 10,
+        purpose:        SpecialPurpose {
+            name:                 "Link-Local Unicast",
+            rfc:                  "RFC 4291",
+            kind:                 SpecialPurposeKind::LinkLocal,
+            source:               true,
+            destination:          true,
+            forwardable:          false,
+            globally_reachable:   false,
+            reserved_by_protocol: true,
+        },
+    },
+    Ipv6SpecialPurposeEntry {
+        address:        Ipv6Addr::new(0xFF00, 0, 0, 0, 0, 0, 0, 0),
+        network_length: 8,
+        purpose:        SpecialPurpose {
+            name:                 "Multicast",
+            rfc:                  "RFC 4291",
+            kind:                 SpecialPurposeKind::Multicast,
+            source:               false,
+            destination:          true,
+            forwardable:          true,
+            globally_reachable:   false,
+            reserved_by_protocol: false,
+        },
+    },
+];
+
+/// To classify IPv6 addresses against the IANA IPv6 special-purpose address registry.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv6Addr, str::FromStr};
+///
+/// use cidr::Ipv6Cidr;
+/// use cidr_utils::registry::Ipv6SpecialPurposeRegistry;
+///
+/// let entry = Ipv6SpecialPurposeRegistry::classify_addr(
+///     &Ipv6Addr::from_str("fd12:3456::1").unwrap(),
+/// )
+/// .unwrap();
+///
+/// assert!(entry.purpose().is_private());
+/// assert_eq!(Ipv6Cidr::from_str("fc00::/7").unwrap(), entry.cidr());
+///
+/// assert!(Ipv6SpecialPurposeRegistry::classify_addr(
+///     &Ipv6Addr::from_str("2606:4700::1111").unwrap()
+/// )
+/// .is_none());
+/// ```
+#[derive(Debug)]
+pub struct Ipv6SpecialPurposeRegistry;
+
+impl Ipv6SpecialPurposeRegistry {
+    /// Get all entries, ordered by their address blocks.
+    #[inline]
+    pub fn entries() -> &'static [Ipv6SpecialPurposeEntry] {
+        &ENTRIES
+    }
+
+    /// Find the most specific entry whose address block contains an IPv6 address.
+    #[inline]
+    pub fn classify_addr(ipv6: &Ipv6Addr) -> Option<&'static Ipv6SpecialPurposeEntry> {
+        ENTRIES.iter().filter(|entry| entry.contains(ipv6)).max_by_key(|entry| entry.network_length)
+    }
+
+    /// Find the most specific entry whose address block contains a whole IPv6 CIDR.
+    #[inline]
+    pub fn classify_cidr(cidr: &Ipv6Cidr) -> Option<&'static Ipv6SpecialPurposeEntry> {
+        ENTRIES
+            .iter()
+            .filter(|entry| {
+                entry.network_length <= cidr.network_length()
+                    && entry.contains(&cidr.first_address())
+            })
+            .max_by_key(|entry| entry.network_length)
+    }
+
+    /// Get a combiner of the bogon address blocks, which are the address blocks not globally reachable. Globally reachable blocks nested in them (e.g. `2001:1::1/128`) are not excluded.
+    pub fn bogons() -> Ipv6CidrCombiner {
+        let mut combiner = Ipv6CidrCombiner::new();
+
+        for entry in ENTRIES.iter().filter(|entry| !entry.purpose.globally_reachable) {
+            combiner.push(entry.cidr());
+        }
+
+        combiner
+    }
+
+    /// Get a combiner of the addresses which are not globally reachable. Unlike `bogons`, globally reachable blocks nested in the other blocks are excluded.
+    pub fn non_global() -> Ipv6CidrCombiner {
+        let mut combiner = Self::bogons();
+
+        for entry in ENTRIES.iter().filter(|entry| entry.purpose.globally_reachable) {
+            combiner.subtract(&entry.cidr());
+        }
+
+        combiner
+    }
+}
@@ -0,0 +1,71 @@
+//! Pick distinct addresses uniformly at random from CIDRs.
+//!
+//! The addresses are picked with Floyd's algorithm, so the cost depends on how many addresses are picked rather than on the size of the CIDRs. The random numbers come from a small SplitMix64 generator seeded by the caller, so the same seed always picks the same addresses.
+
+mod v4;
+mod v6;
+
+use alloc::collections::BTreeSet;
+
+pub use v4::*;
+pub use v6::*;
+
+/// SplitMix64, which is good enough for picking addresses and needs no dependencies.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.0;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+        z ^ (z >> 31)
+    }
+
+    #[inline]
+    fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    /// Get a uniformly random number in `0..=max`.
+    #[inline]
+    fn below_or_eq(&mut self, max: u128) -> u128 {
+        let mask = u128::MAX.checked_shr(max.leading_zeros()).unwrap_or(0);
+
+        // rejection sampling keeps the distribution uniform
+        loop {
+            let x = self.next_u128() & mask;
+
+            if x <= max {
+                return x;
+            }
+        }
+    }
+}
+
+/// Pick `n` distinct offsets in `0..=last`. Returns `None` if there are fewer than `n` offsets.
+fn sample_offsets(last: u128, n: usize, seed: u64) -> Option<BTreeSet<u128>> {
+    let mut offsets = BTreeSet::new();
+
+    if n == 0 {
+        return Some(offsets);
+    }
+
+    let start = last.checked_sub(n as u128 - 1)?;
+
+    let mut rng = SplitMix64(seed);
+
+    for j in start..=last {
+        let t = rng.below_or_eq(j);
+
+        if !offsets.insert(t) {
+            offsets.insert(j);
+        }
+    }
+
+    Some(offsets)
+}
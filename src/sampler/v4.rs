@@ -0,0 +1,55 @@
+use alloc::vec::Vec;
+use core::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+
+use super::sample_offsets;
+use crate::{combiner::Ipv4CidrCombiner, Ipv4CidrSize};
+
+/// To pick random addresses from IPv4 CIDRs.
+#[derive(Debug)]
+pub struct Ipv4CidrSampler;
+
+impl Ipv4CidrSampler {
+    /// Pick `n` distinct addresses uniformly at random from an IPv4 CIDR, in ascending order. Returns `None` if the CIDR has fewer than `n` addresses.
+    pub fn sample(cidr: &Ipv4Cidr, n: usize, seed: u64) -> Option<Vec<Ipv4Addr>> {
+        let first: u32 = cidr.first_address().into();
+
+        let offsets = sample_offsets(cidr.size() as u128 - 1, n, seed)?;
+
+        Some(offsets.into_iter().map(|offset| Ipv4Addr::from(first + offset as u32)).collect())
+    }
+
+    /// Pick `n` distinct addresses uniformly at random from the CIDRs of a combiner, in ascending order. Returns `None` if the CIDRs have fewer than `n` addresses.
+    pub fn sample_combiner(
+        combiner: &Ipv4CidrCombiner,
+        n: usize,
+        seed: u64,
+    ) -> Option<Vec<Ipv4Addr>> {
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        let offsets = sample_offsets(combiner.size().checked_sub(1)? as u128, n, seed)?;
+
+        let mut output = Vec::with_capacity(n);
+
+        let mut cidrs = combiner.iter();
+        let mut cidr = cidrs.next()?;
+        let mut base = 0u64;
+
+        for offset in offsets {
+            let offset = offset as u64;
+
+            // the offsets are ascending, so the CIDRs are walked only once
+            while offset >= base + cidr.size() {
+                base += cidr.size();
+                cidr = cidrs.next()?;
+            }
+
+            output.push(Ipv4Addr::from(u32::from(cidr.first_address()) + (offset - base) as u32));
+        }
+
+        Some(output)
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+
+use super::sample_offsets;
+use crate::{combiner::Ipv6CidrCombiner, Ipv6CidrSizeU128};
+
+/// The offset of the last address of an IPv6 CIDR.
+#[inline]
+fn last_offset(cidr: &Ipv6Cidr) -> u128 {
+    cidr.size_u128_checked().map_or(u128::MAX, |size| size - 1)
+}
+
+/// To pick random addresses from IPv6 CIDRs.
+#[derive(Debug)]
+pub struct Ipv6CidrSampler;
+
+impl Ipv6CidrSampler {
+    /// Pick `n` distinct addresses uniformly at random from an IPv6 CIDR, in ascending order. Returns `None` if the CIDR has fewer than `n` addresses.
+    pub fn sample(cidr: &Ipv6Cidr, n: usize, seed: u64) -> Option<Vec<Ipv6Addr>> {
+        let first: u128 = cidr.first_address().into();
+
+        let offsets = sample_offsets(last_offset(cidr), n, seed)?;
+
+        Some(offsets.into_iter().map(|offset| Ipv6Addr::from(first + offset)).collect())
+    }
+
+    /// Pick `n` distinct addresses uniformly at random from the CIDRs of a combiner, in ascending order. Returns `None` if the CIDRs have fewer than `n` addresses.
+    pub fn sample_combiner(
+        combiner: &Ipv6CidrCombiner,
+        n: usize,
+        seed: u64,
+    ) -> Option<Vec<Ipv6Addr>> {
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        // the CIDRs of a combiner are disjoint, so the sum can only reach 2^128 with `::/0` alone
+        let last = combiner.iter().fold(None, |last: Option<u128>, cidr| match last {
+            Some(last) => Some(last + 1 + last_offset(cidr)),
+            None => Some(last_offset(cidr)),
+        })?;
+
+        let offsets = sample_offsets(last, n, seed)?;
+
+        let mut output = Vec::with_capacity(n);
+
+        let mut cidrs = combiner.iter();
+        let mut cidr = cidrs.next()?;
+        let mut base = 0u128;
+
+        for offset in offsets {
+            // the offsets are ascending, so the CIDRs are walked only once
+            while offset - base > last_offset(cidr) {
+                base += last_offset(cidr) + 1;
+                cidr = cidrs.next()?;
+            }
+
+            output.push(Ipv6Addr::from(u128::from(cidr.first_address()) + (offset - base)));
+        }
+
+        Some(output)
+    }
+}
@@ -1,4 +1,5 @@
-use std::{cmp::Ordering, net::Ipv4Addr};
+use alloc::{vec, vec::Vec};
+use core::{cmp::Ordering, net::Ipv4Addr};
 
 use cidr::Ipv4Cidr;
 
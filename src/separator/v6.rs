@@ -1,10 +1,9 @@
-use std::{cmp::Ordering, net::Ipv6Addr};
+use alloc::{vec, vec::Vec};
+use core::{cmp::Ordering, net::Ipv6Addr};
 
 use cidr::Ipv6Cidr;
-use num_bigint::BigUint;
-use num_traits::ToPrimitive;
 
-use crate::{combiner::Ipv6CidrCombiner, Ipv6CidrSize};
+use crate::combiner::Ipv6CidrCombiner;
 
 /// To divide an IPv6 CIDR into subnetworks.
 #[derive(Debug)]
@@ -13,11 +12,12 @@ pub struct Ipv6CidrSeparator;
 impl Ipv6CidrSeparator {
     /// Evenly divide an IPv6 CIDR into a specific number of subnetworks.
     pub fn divide_by(cidr: &Ipv6Cidr, n: usize) -> Option<Vec<Ipv6CidrCombiner>> {
-        let size = cidr.size();
+        // the size minus one, which always fits in a u128
+        let last_offset = u128::MAX.checked_shr(cidr.network_length() as u32).unwrap_or(0);
 
-        let n_big_int = BigUint::from(n);
+        let n_u128 = n as u128;
 
-        if n == 0 || n_big_int > size {
+        if n == 0 || n_u128 - 1 > last_offset {
             return None;
         } else if n == 1 {
             let mut combiner = Ipv6CidrCombiner::with_capacity(1);
@@ -27,23 +27,24 @@ impl Ipv6CidrSeparator {
             return Some(vec![combiner]);
         }
 
-        let d = &size / &n_big_int;
+        // (last_offset + 1) / n, without overflowing when the size is 2^128
+        let d = last_offset / n_u128 + u128::from(last_offset % n_u128 == n_u128 - 1);
 
         let mut output = Vec::new();
 
         output.try_reserve_exact(n).ok()?;
 
         let mut next_address = cidr.first_address().to_bits();
-        let mut remaining_size = size;
+        let mut remaining_last_offset = last_offset;
 
         for index in 0..n {
-            let chunk_size = if index + 1 == n { remaining_size.to_u128()? } else { d.to_u128()? };
+            let chunk_size = if index + 1 == n { remaining_last_offset + 1 } else { d };
 
             output.push(ipv6_cidr_combiner_from_range(next_address, chunk_size));
 
             if index + 1 != n {
                 next_address = next_address.checked_add(chunk_size)?;
-                remaining_size -= &d;
+                remaining_last_offset -= d;
             }
         }
 
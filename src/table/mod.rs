@@ -0,0 +1,40 @@
+//! Read-only, lookup-optimized byte layouts of combined CIDRs.
+//!
+//! A table is produced from a combiner and can be stored in a file. The bytes can later be borrowed (e.g. from a memory-mapped file) and queried directly, without deserializing them into a combiner.
+//!
+//! The layout is:
+//!
+//! | Offset          | Size                  | Content                                         |
+//! |-----------------|-----------------------|-------------------------------------------------|
+//! | `0`             | `8`                   | magic bytes, `CIDRTV4\0` or `CIDRTV6\0`         |
+//! | `8`             | `8`                   | the number of entries `n`, little-endian `u64`  |
+//! | `16`            | `n * w`               | the first addresses, little-endian, ascending   |
+//! | `16 + n * w`    | `n * w`               | the last addresses, little-endian, ascending    |
+//!
+//! where `w` is `4` for IPv4 and `16` for IPv6.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
+
+const HEADER_LENGTH: usize = 16;
+
+#[inline]
+fn parse_header(bytes: &[u8], magic: &[u8; 8], width: usize) -> Option<usize> {
+    if bytes.len() < HEADER_LENGTH || &bytes[..8] != magic {
+        return None;
+    }
+
+    let length = u64::from_le_bytes(bytes[8..HEADER_LENGTH].try_into().unwrap());
+    let length = usize::try_from(length).ok()?;
+
+    let body_length = length.checked_mul(width)?.checked_mul(2)?;
+
+    if bytes.len() - HEADER_LENGTH != body_length {
+        return None;
+    }
+
+    Some(length)
+}
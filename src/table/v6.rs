@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+
+use super::{parse_header, HEADER_LENGTH};
+use crate::combiner::Ipv6CidrCombiner;
+
+const MAGIC: &[u8; 8] = b"CIDRTV6\0";
+const WIDTH: usize = 16;
+
+/// A read-only, lookup-optimized view of combined IPv6 CIDRs which borrows its bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct Ipv6CidrTable<'a> {
+    bytes:  &'a [u8],
+    length: usize,
+}
+
+impl<'a> Ipv6CidrTable<'a> {
+    /// Encode the CIDRs of a combiner into the table layout.
+    pub fn encode(combiner: &Ipv6CidrCombiner) -> Vec<u8> {
+        let length = combiner.len();
+
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH + length * WIDTH * 2);
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&(length as u64).to_le_bytes());
+
+        for cidr in combiner.iter() {
+            bytes.extend_from_slice(&cidr.first_address().to_bits().to_le_bytes());
+        }
+
+        for cidr in combiner.iter() {
+            bytes.extend_from_slice(&cidr.last_address().to_bits().to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Borrow bytes created by `encode` as a table. Returns `None` if the header or the length of the bytes is invalid.
+    #[inline]
+    pub fn from_bytes(bytes: &'a [u8]) -> Option<Ipv6CidrTable<'a>> {
+        let length = parse_header(bytes, MAGIC, WIDTH)?;
+
+        Some(Ipv6CidrTable {
+            bytes,
+            length,
+        })
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Ipv6CidrTable<'a> {
+    #[inline]
+    fn first(&self, index: usize) -> u128 {
+        let offset = HEADER_LENGTH + index * WIDTH;
+
+        u128::from_le_bytes(self.bytes[offset..offset + WIDTH].try_into().unwrap())
+    }
+
+    #[inline]
+    fn last(&self, index: usize) -> u128 {
+        let offset = HEADER_LENGTH + (self.length + index) * WIDTH;
+
+        u128::from_le_bytes(self.bytes[offset..offset + WIDTH].try_into().unwrap())
+    }
+
+    /// Find the index of the entry whose range includes the address.
+    fn search(&self, ipv6: &Ipv6Addr) -> Option<usize> {
+        let ip = ipv6.to_bits();
+
+        // the number of entries whose first address is not greater than `ip`
+        let mut low = 0;
+        let mut high = self.length;
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if self.first(middle) <= ip {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        if low > 0 && self.last(low - 1) >= ip {
+            Some(low - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of CIDRs in this table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Get a CIDR in this table.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Ipv6Cidr> {
+        if index >= self.length {
+            return None;
+        }
+
+        let first = self.first(index);
+        let host_bits = self.last(index).wrapping_sub(first).count_ones();
+
+        Ipv6Cidr::new(first.into(), 128 - host_bits as u8).ok()
+    }
+
+    /// Check an IPv6 whether it is in these CIDRs.
+    #[inline]
+    pub fn contains(&self, ipv6: &Ipv6Addr) -> bool {
+        self.search(ipv6).is_some()
+    }
+
+    /// Get the CIDR which contains an IPv6. Because the CIDRs of a combiner do not overlap, it is also the longest match.
+    #[inline]
+    pub fn longest_match(&self, ipv6: &Ipv6Addr) -> Option<Ipv6Cidr> {
+        self.search(ipv6).and_then(|index| self.get(index))
+    }
+}
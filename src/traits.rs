@@ -1,4 +1,7 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
 use cidr::{Ipv4Cidr, Ipv6Cidr};
+#[cfg(feature = "bigint")]
 use num_bigint::BigUint;
 
 /// Get the size of an Ipv4Cidr.
@@ -26,7 +29,98 @@ impl Ipv4CidrSize for Ipv4Cidr {
     }
 }
 
-/// Get the size of an Ipv6Cidr.
+/// Get the netmask, wildcard mask, broadcast address and usable host range of an Ipv4Cidr, like `ipcalc` does.
+///
+/// A `/31` CIDR is a point-to-point link, both of whose addresses are usable ([RFC 3021](https://www.rfc-editor.org/rfc/rfc3021)), and a `/32` CIDR is a single host. Neither of them has a broadcast address.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv4Addr, str::FromStr};
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::Ipv4CidrInfo;
+///
+/// let cidr = Ipv4Cidr::from_str("192.168.1.0/24").unwrap();
+///
+/// assert_eq!(Ipv4Addr::new(255, 255, 255, 0), cidr.netmask());
+/// assert_eq!(Ipv4Addr::new(0, 0, 0, 255), cidr.wildcard_mask());
+/// assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 255)), cidr.broadcast());
+/// assert_eq!(Ipv4Addr::new(192, 168, 1, 1), cidr.first_host());
+/// assert_eq!(Ipv4Addr::new(192, 168, 1, 254), cidr.last_host());
+/// assert_eq!(254, cidr.host_count());
+///
+/// let cidr = Ipv4Cidr::from_str("192.168.1.0/31").unwrap();
+///
+/// assert_eq!(None, cidr.broadcast());
+/// assert_eq!(Ipv4Addr::new(192, 168, 1, 0), cidr.first_host());
+/// assert_eq!(Ipv4Addr::new(192, 168, 1, 1), cidr.last_host());
+/// assert_eq!(2, cidr.host_count());
+/// ```
+pub trait Ipv4CidrInfo: Ipv4CidrSize {
+    fn netmask(&self) -> Ipv4Addr;
+
+    fn wildcard_mask(&self) -> Ipv4Addr;
+
+    /// Returns `None` for `/31` and `/32` CIDRs.
+    fn broadcast(&self) -> Option<Ipv4Addr>;
+
+    fn first_host(&self) -> Ipv4Addr;
+
+    fn last_host(&self) -> Ipv4Addr;
+
+    fn host_count(&self) -> u64;
+}
+
+impl Ipv4CidrInfo for Ipv4Cidr {
+    #[inline]
+    fn netmask(&self) -> Ipv4Addr {
+        self.mask()
+    }
+
+    #[inline]
+    fn wildcard_mask(&self) -> Ipv4Addr {
+        !self.mask()
+    }
+
+    #[inline]
+    fn broadcast(&self) -> Option<Ipv4Addr> {
+        if self.network_length() >= 31 {
+            None
+        } else {
+            Some(self.last_address())
+        }
+    }
+
+    #[inline]
+    fn first_host(&self) -> Ipv4Addr {
+        if self.network_length() >= 31 {
+            self.first_address()
+        } else {
+            Ipv4Addr::from(self.first_address().to_bits() + 1)
+        }
+    }
+
+    #[inline]
+    fn last_host(&self) -> Ipv4Addr {
+        if self.network_length() >= 31 {
+            self.last_address()
+        } else {
+            Ipv4Addr::from(self.last_address().to_bits() - 1)
+        }
+    }
+
+    #[inline]
+    fn host_count(&self) -> u64 {
+        if self.network_length() >= 31 {
+            self.size()
+        } else {
+            self.size() - 2
+        }
+    }
+}
+
+/// Get the size of an Ipv6Cidr as a `BigUint`. It needs the `bigint` feature.
 ///
 /// # Examples
 ///
@@ -41,13 +135,370 @@ impl Ipv4CidrSize for Ipv4Cidr {
 ///
 /// assert_eq!(BigUint::from(65536usize), cidr.size());
 /// ```
+#[cfg(feature = "bigint")]
 pub trait Ipv6CidrSize {
     fn size(&self) -> BigUint;
 }
 
+#[cfg(feature = "bigint")]
 impl Ipv6CidrSize for Ipv6Cidr {
     #[inline]
     fn size(&self) -> BigUint {
         BigUint::from(2u8).pow((128 - self.network_length()) as u32)
     }
 }
+
+/// Get the size of an Ipv6Cidr as a `u128`, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv6Cidr;
+/// use cidr_utils::Ipv6CidrSizeU128;
+///
+/// let cidr = Ipv6Cidr::from_str("0:0:0:0:0:FFFF:FFFF:0/112").unwrap();
+///
+/// assert_eq!(Some(65536), cidr.size_u128_checked());
+/// assert_eq!(16, cidr.log2_size());
+/// ```
+pub trait Ipv6CidrSizeU128 {
+    /// Get the size. Returns `None` only for `::/0`, whose size is `2^128`.
+    fn size_u128_checked(&self) -> Option<u128>;
+
+    /// Get the base-2 logarithm of the size, which is the number of host bits.
+    fn log2_size(&self) -> u8;
+}
+
+impl Ipv6CidrSizeU128 for Ipv6Cidr {
+    #[inline]
+    fn size_u128_checked(&self) -> Option<u128> {
+        1u128.checked_shl(self.log2_size() as u32)
+    }
+
+    #[inline]
+    fn log2_size(&self) -> u8 {
+        128 - self.network_length()
+    }
+}
+
+/// Get the netmask, address range and interface identifier length of an Ipv6Cidr. IPv6 has no broadcast address, so every address of the CIDR is a usable host.
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv6Addr, str::FromStr};
+///
+/// use cidr::Ipv6Cidr;
+/// use cidr_utils::Ipv6CidrInfo;
+///
+/// let cidr = Ipv6Cidr::from_str("2001:db8::/64").unwrap();
+///
+/// assert_eq!(
+///     Ipv6Addr::from_str("ffff:ffff:ffff:ffff::").unwrap(),
+///     cidr.netmask()
+/// );
+/// assert_eq!(
+///     Ipv6Addr::from_str("::ffff:ffff:ffff:ffff").unwrap(),
+///     cidr.wildcard_mask()
+/// );
+/// assert_eq!(Ipv6Addr::from_str("2001:db8::").unwrap(), cidr.first_host());
+/// assert_eq!(
+///     Ipv6Addr::from_str("2001:db8::ffff:ffff:ffff:ffff").unwrap(),
+///     cidr.last_host()
+/// );
+/// assert_eq!(64, cidr.interface_identifier_bits());
+/// ```
+pub trait Ipv6CidrInfo: Ipv6CidrSizeU128 {
+    fn netmask(&self) -> Ipv6Addr;
+
+    fn wildcard_mask(&self) -> Ipv6Addr;
+
+    fn first_host(&self) -> Ipv6Addr;
+
+    /// Get the last address.
+    fn last_host(&self) -> Ipv6Addr;
+
+    /// Get the number of bits which are not fixed by the network prefix.
+    fn interface_identifier_bits(&self) -> u8;
+}
+
+impl Ipv6CidrInfo for Ipv6Cidr {
+    #[inline]
+    fn netmask(&self) -> Ipv6Addr {
+        self.mask()
+    }
+
+    #[inline]
+    fn wildcard_mask(&self) -> Ipv6Addr {
+        !self.mask()
+    }
+
+    #[inline]
+    fn first_host(&self) -> Ipv6Addr {
+        self.first_address()
+    }
+
+    #[inline]
+    fn last_host(&self) -> Ipv6Addr {
+        self.last_address()
+    }
+
+    #[inline]
+    fn interface_identifier_bits(&self) -> u8 {
+        128 - self.network_length()
+    }
+}
+
+/// Navigate from an Ipv4Cidr to its parent, sibling, children, supernetworks and neighboring blocks. Every method returns `None` instead of overflowing at the top or the bottom of the address space.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::Ipv4CidrNavigation;
+///
+/// let cidr = Ipv4Cidr::from_str("10.0.1.0/24").unwrap();
+///
+/// assert_eq!(Some(Ipv4Cidr::from_str("10.0.0.0/23").unwrap()), cidr.parent());
+/// assert_eq!(
+///     Some(Ipv4Cidr::from_str("10.0.0.0/24").unwrap()),
+///     cidr.sibling()
+/// );
+/// assert_eq!(
+///     Some(Ipv4Cidr::from_str("10.0.0.0/16").unwrap()),
+///     cidr.supernet(16)
+/// );
+/// assert_eq!(
+///     Some(Ipv4Cidr::from_str("10.0.2.0/24").unwrap()),
+///     cidr.next_block()
+/// );
+/// assert_eq!(
+///     Some(Ipv4Cidr::from_str("10.0.0.0/24").unwrap()),
+///     cidr.prev_block()
+/// );
+/// assert_eq!(
+///     Ipv4Cidr::from_str("10.0.0.0/14").unwrap(),
+///     cidr.common_supernet(&Ipv4Cidr::from_str("10.3.0.0/16").unwrap())
+/// );
+///
+/// let (low, high) = cidr.children().unwrap();
+///
+/// assert_eq!(Ipv4Cidr::from_str("10.0.1.0/25").unwrap(), low);
+/// assert_eq!(Ipv4Cidr::from_str("10.0.1.128/25").unwrap(), high);
+/// ```
+pub trait Ipv4CidrNavigation: Sized {
+    /// Returns `None` for `/0`.
+    fn parent(&self) -> Option<Self>;
+
+    /// Get the other half of the parent. Returns `None` for `/0`.
+    fn sibling(&self) -> Option<Self>;
+
+    /// Get the lower and upper halves. Returns `None` for `/32`.
+    fn children(&self) -> Option<(Self, Self)>;
+
+    /// Get the supernetwork with a shorter or equal network length. Returns `None` if the network length is longer.
+    fn supernet(&self, network_length: u8) -> Option<Self>;
+
+    /// Get the smallest CIDR which contains both CIDRs.
+    fn common_supernet(&self, other: &Self) -> Self;
+
+    /// Get the following block with the same network length.
+    fn next_block(&self) -> Option<Self>;
+
+    /// Get the preceding block with the same network length.
+    fn prev_block(&self) -> Option<Self>;
+}
+
+impl Ipv4CidrNavigation for Ipv4Cidr {
+    #[inline]
+    fn parent(&self) -> Option<Self> {
+        self.supernet(self.network_length().checked_sub(1)?)
+    }
+
+    #[inline]
+    fn sibling(&self) -> Option<Self> {
+        let bits = self.network_length().checked_sub(1)?;
+
+        let prefix = self.first_address().to_bits() ^ (1 << (31 - bits));
+
+        Some(Ipv4Cidr::new(Ipv4Addr::from(prefix), self.network_length()).unwrap())
+    }
+
+    #[inline]
+    fn children(&self) -> Option<(Self, Self)> {
+        let bits = self.network_length();
+
+        if bits >= 32 {
+            return None;
+        }
+
+        let prefix = self.first_address().to_bits();
+
+        Some((
+            Ipv4Cidr::new(Ipv4Addr::from(prefix), bits + 1).unwrap(),
+            Ipv4Cidr::new(Ipv4Addr::from(prefix | (1 << (31 - bits))), bits + 1).unwrap(),
+        ))
+    }
+
+    #[inline]
+    fn supernet(&self, network_length: u8) -> Option<Self> {
+        if network_length > self.network_length() {
+            return None;
+        }
+
+        let mask = u32::MAX.checked_shl(32 - network_length as u32).unwrap_or(0);
+
+        Some(
+            Ipv4Cidr::new(Ipv4Addr::from(self.first_address().to_bits() & mask), network_length)
+                .unwrap(),
+        )
+    }
+
+    #[inline]
+    fn common_supernet(&self, other: &Self) -> Self {
+        let d = self.first_address().to_bits() ^ other.first_address().to_bits();
+
+        let bits = self.network_length().min(other.network_length()).min(d.leading_zeros() as u8);
+
+        self.supernet(bits).unwrap()
+    }
+
+    #[inline]
+    fn next_block(&self) -> Option<Self> {
+        let prefix = self.last_address().to_bits().checked_add(1)?;
+
+        Some(Ipv4Cidr::new(Ipv4Addr::from(prefix), self.network_length()).unwrap())
+    }
+
+    #[inline]
+    fn prev_block(&self) -> Option<Self> {
+        let size = u32::try_from(self.size()).ok()?;
+
+        let prefix = self.first_address().to_bits().checked_sub(size)?;
+
+        Some(Ipv4Cidr::new(Ipv4Addr::from(prefix), self.network_length()).unwrap())
+    }
+}
+
+/// Navigate from an Ipv6Cidr to its parent, sibling, children, supernetworks and neighboring blocks. Every method returns `None` instead of overflowing at the top or the bottom of the address space.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv6Cidr;
+/// use cidr_utils::Ipv6CidrNavigation;
+///
+/// let cidr = Ipv6Cidr::from_str("2001:db8:1::/48").unwrap();
+///
+/// assert_eq!(
+///     Some(Ipv6Cidr::from_str("2001:db8::/47").unwrap()),
+///     cidr.parent()
+/// );
+/// assert_eq!(
+///     Some(Ipv6Cidr::from_str("2001:db8::/48").unwrap()),
+///     cidr.sibling()
+/// );
+/// assert_eq!(
+///     Some(Ipv6Cidr::from_str("2001:db8:2::/48").unwrap()),
+///     cidr.next_block()
+/// );
+/// ```
+pub trait Ipv6CidrNavigation: Sized {
+    /// Returns `None` for `/0`.
+    fn parent(&self) -> Option<Self>;
+
+    /// Get the other half of the parent. Returns `None` for `/0`.
+    fn sibling(&self) -> Option<Self>;
+
+    /// Get the lower and upper halves. Returns `None` for `/128`.
+    fn children(&self) -> Option<(Self, Self)>;
+
+    /// Get the supernetwork with a shorter or equal network length. Returns `None` if the network length is longer.
+    fn supernet(&self, network_length: u8) -> Option<Self>;
+
+    /// Get the smallest CIDR which contains both CIDRs.
+    fn common_supernet(&self, other: &Self) -> Self;
+
+    /// Get the following block with the same network length.
+    fn next_block(&self) -> Option<Self>;
+
+    /// Get the preceding block with the same network length.
+    fn prev_block(&self) -> Option<Self>;
+}
+
+impl Ipv6CidrNavigation for Ipv6Cidr {
+    #[inline]
+    fn parent(&self) -> Option<Self> {
+        self.supernet(self.network_length().checked_sub(1)?)
+    }
+
+    #[inline]
+    fn sibling(&self) -> Option<Self> {
+        let bits = self.network_length().checked_sub(1)?;
+
+        let prefix = self.first_address().to_bits() ^ (1 << (127 - bits));
+
+        Some(Ipv6Cidr::new(Ipv6Addr::from(prefix), self.network_length()).unwrap())
+    }
+
+    #[inline]
+    fn children(&self) -> Option<(Self, Self)> {
+        let bits = self.network_length();
+
+        if bits >= 128 {
+            return None;
+        }
+
+        let prefix = self.first_address().to_bits();
+
+        Some((
+            Ipv6Cidr::new(Ipv6Addr::from(prefix), bits + 1).unwrap(),
+            Ipv6Cidr::new(Ipv6Addr::from(prefix | (1 << (127 - bits))), bits + 1).unwrap(),
+        ))
+    }
+
+    #[inline]
+    fn supernet(&self, network_length: u8) -> Option<Self> {
+        if network_length > self.network_length() {
+            return None;
+        }
+
+        let mask = u128::MAX.checked_shl(128 - network_length as u32).unwrap_or(0);
+
+        Some(
+            Ipv6Cidr::new(Ipv6Addr::from(self.first_address().to_bits() & mask), network_length)
+                .unwrap(),
+        )
+    }
+
+    #[inline]
+    fn common_supernet(&self, other: &Self) -> Self {
+        let d = self.first_address().to_bits() ^ other.first_address().to_bits();
+
+        let bits = self.network_length().min(other.network_length()).min(d.leading_zeros() as u8);
+
+        self.supernet(bits).unwrap()
+    }
+
+    #[inline]
+    fn next_block(&self) -> Option<Self> {
+        let prefix = self.last_address().to_bits().checked_add(1)?;
+
+        Some(Ipv6Cidr::new(Ipv6Addr::from(prefix), self.network_length()).unwrap())
+    }
+
+    #[inline]
+    fn prev_block(&self) -> Option<Self> {
+        let size = self.size_u128_checked()?;
+
+        let prefix = self.first_address().to_bits().checked_sub(size)?;
+
+        Some(Ipv6Cidr::new(Ipv6Addr::from(prefix), self.network_length()).unwrap())
+    }
+}
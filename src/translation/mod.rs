@@ -0,0 +1,7 @@
+//! Translate between IPv4 and IPv6 CIDRs for IPv4-embedded IPv6 addresses, and decode the IPv4 addresses tunneled in IPv6 addresses.
+
+mod nat64;
+mod tunnel;
+
+pub use nat64::*;
+pub use tunnel::*;
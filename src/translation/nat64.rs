@@ -0,0 +1,270 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+
+use crate::combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner};
+
+/// The mask of bits 64 to 71 of an IPv6 address (the "u" octet of [RFC 6052](https://www.rfc-editor.org/rfc/rfc6052)).
+const U_OCTET_MASK: u128 = 0xFF << 56;
+
+/// An IPv6 prefix which embeds IPv4 addresses by the rules of [RFC 6052](https://www.rfc-editor.org/rfc/rfc6052#section-2.2).
+///
+/// # Examples
+///
+/// ```
+/// use std::{net::Ipv4Addr, str::FromStr};
+///
+/// use cidr::{Ipv4Cidr, Ipv6Cidr};
+/// use cidr_utils::translation::Nat64Prefix;
+///
+/// let prefix = Nat64Prefix::well_known();
+///
+/// assert_eq!(
+///     "64:ff9b::c000:221",
+///     prefix.embed_addr(&Ipv4Addr::new(192, 0, 2, 33)).to_string()
+/// );
+///
+/// let prefix =
+///     Nat64Prefix::new(Ipv6Cidr::from_str("2001:db8:100::/40").unwrap())
+///         .unwrap();
+///
+/// assert_eq!(
+///     "2001:db8:1c0:2:21::",
+///     prefix.embed_addr(&Ipv4Addr::new(192, 0, 2, 33)).to_string()
+/// );
+/// assert_eq!(
+///     Ipv6Cidr::from_str("2001:db8:1c0:2::/72").unwrap(),
+///     prefix.embed_cidr(&Ipv4Cidr::from_str("192.0.2.0/24").unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nat64Prefix {
+    prefix: Ipv6Cidr,
+}
+
+impl Nat64Prefix {
+    /// Create a new `Nat64Prefix` instance. The network length must be one of `32`, `40`, `48`, `56`, `64` and `96`, and bits 64 to 71 must be zero.
+    #[inline]
+    pub fn new(prefix: Ipv6Cidr) -> Option<Nat64Prefix> {
+        if !matches!(prefix.network_length(), 32 | 40 | 48 | 56 | 64 | 96) {
+            return None;
+        }
+
+        if prefix.first_address().to_bits() & U_OCTET_MASK != 0 {
+            return None;
+        }
+
+        Some(Nat64Prefix {
+            prefix,
+        })
+    }
+
+    /// Get the well-known prefix `64:ff9b::/96`.
+    #[inline]
+    pub fn well_known() -> Nat64Prefix {
+        Nat64Prefix {
+            prefix: Ipv6Cidr::new(Ipv6Addr::new(0x64, 0xFF9B, 0, 0, 0, 0, 0, 0), 96).unwrap(),
+        }
+    }
+
+    #[inline]
+    pub fn prefix(&self) -> Ipv6Cidr {
+        self.prefix
+    }
+
+    /// The number of bits of the IPv4 address which are placed before the "u" octet.
+    #[inline]
+    fn bits_before_u(&self) -> u8 {
+        64u8.saturating_sub(self.prefix.network_length()).min(32)
+    }
+
+    #[inline]
+    fn embed_bits(&self, ipv4: u32) -> u128 {
+        let prefix = self.prefix.first_address().to_bits();
+        let ipv4 = ipv4 as u128;
+
+        if self.prefix.network_length() == 96 {
+            return prefix | ipv4;
+        }
+
+        let before = self.bits_before_u() as u32;
+
+        let high = ipv4 >> (32 - before);
+        let low = ipv4 & ((1 << (32 - before)) - 1);
+
+        prefix | (high << 64) | (low << (24 + before))
+    }
+
+    #[inline]
+    fn extract_bits(&self, ipv6: u128) -> u32 {
+        if self.prefix.network_length() == 96 {
+            return ipv6 as u32;
+        }
+
+        let before = self.bits_before_u() as u32;
+
+        let high = (ipv6 >> 64) & ((1 << before) - 1);
+        let low = (ipv6 >> (24 + before)) & ((1 << (32 - before)) - 1);
+
+        ((high << (32 - before)) | low) as u32
+    }
+
+    /// Embed an IPv4 address into an IPv6 address. The suffix bits are zero.
+    #[inline]
+    pub fn embed_addr(&self, ipv4: &Ipv4Addr) -> Ipv6Addr {
+        Ipv6Addr::from(self.embed_bits(ipv4.to_bits()))
+    }
+
+    /// Extract the embedded IPv4 address from an IPv6 address. Returns `None` if the IPv6 address is not in this prefix, or its "u" octet is not zero.
+    #[inline]
+    pub fn extract_addr(&self, ipv6: &Ipv6Addr) -> Option<Ipv4Addr> {
+        if !self.prefix.contains(ipv6) {
+            return None;
+        }
+
+        let bits = ipv6.to_bits();
+
+        if bits & U_OCTET_MASK != 0 {
+            return None;
+        }
+
+        Some(Ipv4Addr::from(self.extract_bits(bits)))
+    }
+
+    /// Embed an IPv4 CIDR into the smallest IPv6 CIDR which covers the embedded addresses of all its addresses.
+    #[inline]
+    pub fn embed_cidr(&self, cidr: &Ipv4Cidr) -> Ipv6Cidr {
+        let prefix_bits = self.prefix.network_length();
+        let bits = cidr.network_length();
+
+        // skip the "u" octet once the IPv4 bits reach it
+        let ipv6_bits = if prefix_bits < 96 && bits >= self.bits_before_u() {
+            prefix_bits + bits + 8
+        } else {
+            prefix_bits + bits
+        };
+
+        Ipv6Cidr::new(self.embed_addr(&cidr.first_address()), ipv6_bits).unwrap()
+    }
+
+    /// Extract the IPv4 CIDR whose addresses are embedded in an IPv6 CIDR. Returns `None` if the IPv6 CIDR does not overlap this prefix, or its "u" octet is fixed to a value other than zero.
+    pub fn extract_cidr(&self, cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        let prefix_bits = self.prefix.network_length();
+        let ipv6_bits = cidr.network_length();
+
+        if ipv6_bits < prefix_bits {
+            return if cidr.contains(&self.prefix.first_address()) {
+                Some(Ipv4Cidr::new(Ipv4Addr::UNSPECIFIED, 0).unwrap())
+            } else {
+                None
+            };
+        }
+
+        let first = cidr.first_address();
+
+        if !self.prefix.contains(&first) {
+            return None;
+        }
+
+        let first = first.to_bits();
+
+        if first & U_OCTET_MASK != 0 {
+            return None;
+        }
+
+        let before = self.bits_before_u();
+
+        let bits = if prefix_bits == 96 {
+            ipv6_bits - 96
+        } else if ipv6_bits <= prefix_bits + before {
+            ipv6_bits - prefix_bits
+        } else if ipv6_bits < prefix_bits + before + 8 {
+            before
+        } else {
+            (ipv6_bits - prefix_bits - 8).min(32)
+        };
+
+        let mask = u32::MAX.checked_shl(32 - bits as u32).unwrap_or(0);
+
+        Some(Ipv4Cidr::new(Ipv4Addr::from(self.extract_bits(first) & mask), bits).unwrap())
+    }
+
+    /// Embed all CIDRs of an IPv4 combiner.
+    pub fn embed_combiner(&self, combiner: &Ipv4CidrCombiner) -> Ipv6CidrCombiner {
+        let mut output = Ipv6CidrCombiner::with_capacity(combiner.len());
+
+        for cidr in combiner.iter() {
+            output.push(self.embed_cidr(cidr));
+        }
+
+        output
+    }
+
+    /// Extract the embedded IPv4 CIDRs of an IPv6 combiner. CIDRs which do not overlap this prefix are ignored.
+    pub fn extract_combiner(&self, combiner: &Ipv6CidrCombiner) -> Ipv4CidrCombiner {
+        let mut output = Ipv4CidrCombiner::new();
+
+        for cidr in combiner.iter() {
+            if let Some(cidr) = self.extract_cidr(cidr) {
+                output.push(cidr);
+            }
+        }
+
+        output
+    }
+}
+
+/// To convert between IPv4 CIDRs and IPv4-mapped IPv6 CIDRs (`::ffff:0:0/96`).
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::{Ipv4Cidr, Ipv6Cidr};
+/// use cidr_utils::translation::Ipv4Mapped;
+///
+/// let cidr = Ipv4Cidr::from_str("192.168.0.0/16").unwrap();
+///
+/// assert_eq!(
+///     Ipv6Cidr::from_str("::ffff:192.168.0.0/112").unwrap(),
+///     Ipv4Mapped::to_ipv6_cidr(&cidr)
+/// );
+/// assert_eq!(
+///     Some(cidr),
+///     Ipv4Mapped::from_ipv6_cidr(&Ipv4Mapped::to_ipv6_cidr(&cidr))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Ipv4Mapped;
+
+impl Ipv4Mapped {
+    #[inline]
+    fn prefix() -> Nat64Prefix {
+        Nat64Prefix {
+            prefix: Ipv6Cidr::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xFFFF, 0, 0), 96).unwrap(),
+        }
+    }
+
+    #[inline]
+    pub fn to_ipv6_cidr(cidr: &Ipv4Cidr) -> Ipv6Cidr {
+        Self::prefix().embed_cidr(cidr)
+    }
+
+    /// Returns `None` if the IPv6 CIDR does not overlap `::ffff:0:0/96`.
+    #[inline]
+    pub fn from_ipv6_cidr(cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        Self::prefix().extract_cidr(cidr)
+    }
+
+    #[inline]
+    pub fn to_ipv6_combiner(combiner: &Ipv4CidrCombiner) -> Ipv6CidrCombiner {
+        Self::prefix().embed_combiner(combiner)
+    }
+
+    /// CIDRs which do not overlap `::ffff:0:0/96` are ignored.
+    #[inline]
+    pub fn from_ipv6_combiner(combiner: &Ipv6CidrCombiner) -> Ipv4CidrCombiner {
+        Self::prefix().extract_combiner(combiner)
+    }
+}
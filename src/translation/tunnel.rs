@@ -0,0 +1,253 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+
+#[inline]
+fn ipv4_cidr(bits: u32, network_length: u8) -> Ipv4Cidr {
+    let mask = u32::MAX.checked_shl(32 - network_length as u32).unwrap_or(0);
+
+    Ipv4Cidr::new(Ipv4Addr::from(bits & mask), network_length).unwrap()
+}
+
+/// Get the network length of the embedded IPv4 CIDR whose bits start at `offset` of an IPv6 CIDR in `prefix`.
+#[inline]
+fn embedded_network_length(prefix: &Ipv6Cidr, cidr: &Ipv6Cidr, offset: u8) -> Option<u8> {
+    if cidr.network_length() < prefix.network_length() {
+        return if cidr.contains(&prefix.first_address()) { Some(0) } else { None };
+    }
+
+    if !prefix.contains(&cidr.first_address()) {
+        return None;
+    }
+
+    Some(cidr.network_length().saturating_sub(offset).min(32))
+}
+
+/// To decode 6to4 addresses (`2002::/16`, [RFC 3056](https://www.rfc-editor.org/rfc/rfc3056)), which embed an IPv4 address in bits 16 to 47.
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     net::{Ipv4Addr, Ipv6Addr},
+///     str::FromStr,
+/// };
+///
+/// use cidr_utils::translation::SixToFour;
+///
+/// assert_eq!(
+///     Some(Ipv4Addr::new(192, 0, 2, 4)),
+///     SixToFour::extract_addr(
+///         &Ipv6Addr::from_str("2002:c000:204::1").unwrap()
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct SixToFour;
+
+impl SixToFour {
+    #[inline]
+    fn prefix() -> Ipv6Cidr {
+        Ipv6Cidr::new(Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0), 16).unwrap()
+    }
+
+    /// Returns `None` if the address is not in `2002::/16`.
+    #[inline]
+    pub fn extract_addr(ipv6: &Ipv6Addr) -> Option<Ipv4Addr> {
+        if !Self::prefix().contains(ipv6) {
+            return None;
+        }
+
+        Some(Ipv4Addr::from((ipv6.to_bits() >> 80) as u32))
+    }
+
+    /// Returns `None` if the CIDR does not overlap `2002::/16`.
+    #[inline]
+    pub fn extract_cidr(cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        let bits = embedded_network_length(&Self::prefix(), cidr, 16)?;
+
+        Some(ipv4_cidr((cidr.first_address().to_bits() >> 80) as u32, bits))
+    }
+
+    /// Get the 6to4 prefix of an IPv4 CIDR. The 6to4 prefix of a single IPv4 address is a `/48`.
+    #[inline]
+    pub fn to_ipv6_cidr(cidr: &Ipv4Cidr) -> Ipv6Cidr {
+        let bits = Self::prefix().first_address().to_bits()
+            | ((cidr.first_address().to_bits() as u128) << 80);
+
+        Ipv6Cidr::new(Ipv6Addr::from(bits), 16 + cidr.network_length()).unwrap()
+    }
+}
+
+/// The fields of a Teredo address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TeredoAddress {
+    /// The IPv4 address of the Teredo server.
+    pub server: Ipv4Addr,
+    /// The external IPv4 address of the Teredo client.
+    pub client: Ipv4Addr,
+    /// The external UDP port of the Teredo client.
+    pub port:   u16,
+    pub flags:  u16,
+}
+
+/// To decode Teredo addresses (`2001::/32`, [RFC 4380](https://www.rfc-editor.org/rfc/rfc4380)), which embed the server address in bits 32 to 63, and the obfuscated client port and client address in the last 48 bits.
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     net::{Ipv4Addr, Ipv6Addr},
+///     str::FromStr,
+/// };
+///
+/// use cidr_utils::translation::Teredo;
+///
+/// let teredo = Teredo::decode(
+///     &Ipv6Addr::from_str("2001:0:4136:e378:8000:63bf:3fff:fdd2").unwrap(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(Ipv4Addr::new(65, 54, 227, 120), teredo.server);
+/// assert_eq!(Ipv4Addr::new(192, 0, 2, 45), teredo.client);
+/// assert_eq!(40000, teredo.port);
+/// ```
+#[derive(Debug)]
+pub struct Teredo;
+
+impl Teredo {
+    #[inline]
+    fn prefix() -> Ipv6Cidr {
+        Ipv6Cidr::new(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 32).unwrap()
+    }
+
+    /// Returns `None` if the address is not in `2001::/32`.
+    #[inline]
+    pub fn decode(ipv6: &Ipv6Addr) -> Option<TeredoAddress> {
+        if !Self::prefix().contains(ipv6) {
+            return None;
+        }
+
+        let bits = ipv6.to_bits();
+
+        Some(TeredoAddress {
+            server: Ipv4Addr::from((bits >> 64) as u32),
+            client: Ipv4Addr::from(!(bits as u32)),
+            port:   !((bits >> 32) as u16),
+            flags:  (bits >> 48) as u16,
+        })
+    }
+
+    /// Get the IPv4 CIDR of the Teredo servers of the addresses in an IPv6 CIDR. Returns `None` if the CIDR does not overlap `2001::/32`.
+    #[inline]
+    pub fn server_cidr(cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        let bits = embedded_network_length(&Self::prefix(), cidr, 32)?;
+
+        Some(ipv4_cidr((cidr.first_address().to_bits() >> 64) as u32, bits))
+    }
+
+    /// Get the IPv4 CIDR of the Teredo clients of the addresses in an IPv6 CIDR. Returns `None` if the CIDR does not overlap `2001::/32`.
+    #[inline]
+    pub fn client_cidr(cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        let bits = embedded_network_length(&Self::prefix(), cidr, 96)?;
+
+        Some(ipv4_cidr(!(cidr.first_address().to_bits() as u32), bits))
+    }
+}
+
+/// To decode ISATAP interface identifiers ([RFC 5214](https://www.rfc-editor.org/rfc/rfc5214)), which are `0000:5efe` or `0200:5efe` followed by an IPv4 address, under any `/64` prefix.
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     net::{Ipv4Addr, Ipv6Addr},
+///     str::FromStr,
+/// };
+///
+/// use cidr_utils::translation::Isatap;
+///
+/// assert_eq!(
+///     Some(Ipv4Addr::new(192, 0, 2, 143)),
+///     Isatap::extract_addr(
+///         &Ipv6Addr::from_str("fe80::5efe:c000:28f").unwrap()
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Isatap;
+
+impl Isatap {
+    #[inline]
+    fn is_isatap_identifier(bits: u128) -> bool {
+        let marker = (bits >> 32) as u32;
+
+        marker == 0x0000_5EFE || marker == 0x0200_5EFE
+    }
+
+    /// Returns `None` if the interface identifier of the address is not an ISATAP one.
+    #[inline]
+    pub fn extract_addr(ipv6: &Ipv6Addr) -> Option<Ipv4Addr> {
+        let bits = ipv6.to_bits();
+
+        if !Self::is_isatap_identifier(bits) {
+            return None;
+        }
+
+        Some(Ipv4Addr::from(bits as u32))
+    }
+
+    /// Returns `None` if the CIDR does not fix an ISATAP interface identifier marker, that is, its network length is shorter than `96` or the marker is not an ISATAP one.
+    #[inline]
+    pub fn extract_cidr(cidr: &Ipv6Cidr) -> Option<Ipv4Cidr> {
+        let bits = cidr.first_address().to_bits();
+
+        if cidr.network_length() < 96 || !Self::is_isatap_identifier(bits) {
+            return None;
+        }
+
+        Some(ipv4_cidr(bits as u32, cidr.network_length() - 96))
+    }
+}
+
+/// The IPv6 transition mechanisms which tunnel IPv4 addresses in IPv6 addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ipv6Tunnel {
+    SixToFour,
+    Teredo,
+    Isatap,
+}
+
+impl Ipv6Tunnel {
+    /// Detect the tunnel mechanism of an IPv6 address and extract the IPv4 address of the tunnel endpoint (for Teredo, the client address).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{net::Ipv6Addr, str::FromStr};
+    ///
+    /// use cidr::Ipv4Cidr;
+    /// use cidr_utils::{combiner::Ipv4CidrCombiner, translation::Ipv6Tunnel};
+    ///
+    /// let mut combiner = Ipv4CidrCombiner::new();
+    ///
+    /// combiner.push(Ipv4Cidr::from_str("192.0.2.0/24").unwrap());
+    ///
+    /// let (tunnel, ipv4) =
+    ///     Ipv6Tunnel::detect(&Ipv6Addr::from_str("2002:c000:204::1").unwrap())
+    ///         .unwrap();
+    ///
+    /// assert_eq!(Ipv6Tunnel::SixToFour, tunnel);
+    /// assert!(combiner.contains(&ipv4));
+    /// ```
+    #[inline]
+    pub fn detect(ipv6: &Ipv6Addr) -> Option<(Ipv6Tunnel, Ipv4Addr)> {
+        if let Some(ipv4) = SixToFour::extract_addr(ipv6) {
+            Some((Ipv6Tunnel::SixToFour, ipv4))
+        } else if let Some(teredo) = Teredo::decode(ipv6) {
+            Some((Ipv6Tunnel::Teredo, teredo.client))
+        } else {
+            Isatap::extract_addr(ipv6).map(|ipv4| (Ipv6Tunnel::Isatap, ipv4))
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Report how much of a parent CIDR is used by the CIDRs of a combiner.
+//!
+//! The fragmentation is `1 - largest free block / free addresses`. It is `0` when the free space is a single aligned block (or there is no free space), and approaches `1` as the free space is scattered into small blocks.
+
+mod v4;
+mod v6;
+
+pub use v4::*;
+pub use v6::*;
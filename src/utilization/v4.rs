@@ -0,0 +1,143 @@
+use cidr::Ipv4Cidr;
+
+use crate::{combiner::Ipv4CidrCombiner, Ipv4CidrSize};
+
+/// The utilization of a parent IPv4 CIDR.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use cidr::Ipv4Cidr;
+/// use cidr_utils::{
+///     combiner::Ipv4CidrCombiner, utilization::Ipv4Utilization,
+/// };
+///
+/// let mut combiner = Ipv4CidrCombiner::new();
+///
+/// combiner.push(Ipv4Cidr::from_str("10.4.0.0/26").unwrap());
+/// combiner.push(Ipv4Cidr::from_str("10.4.0.128/25").unwrap());
+///
+/// let utilization = Ipv4Utilization::new(
+///     &combiner,
+///     &Ipv4Cidr::from_str("10.4.0.0/24").unwrap(),
+/// );
+///
+/// assert_eq!(192, utilization.used());
+/// assert_eq!(75.0, utilization.percentage());
+/// assert_eq!(
+///     Some(Ipv4Cidr::from_str("10.4.0.64/26").unwrap()),
+///     utilization.largest_free_block()
+/// );
+/// assert_eq!(1, utilization.histogram()[25]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ipv4Utilization {
+    parent:             Ipv4Cidr,
+    used:               u64,
+    entries:            usize,
+    free_blocks:        usize,
+    largest_free_block: Option<Ipv4Cidr>,
+    histogram:          [usize; 33],
+}
+
+impl Ipv4Utilization {
+    /// Compute the utilization of a parent CIDR by the CIDRs of a combiner. The CIDRs are clipped to the parent.
+    pub fn new(combiner: &Ipv4CidrCombiner, parent: &Ipv4Cidr) -> Ipv4Utilization {
+        let first = parent.first_address();
+        let last = parent.last_address();
+
+        let start = combiner.partition_point(|c| c.last_address() < first);
+
+        let mut used = 0;
+        let mut entries = 0;
+        let mut histogram = [0; 33];
+
+        for cidr in combiner[start..].iter().take_while(|c| c.first_address() <= last) {
+            let cidr = if cidr.network_length() < parent.network_length() { parent } else { cidr };
+
+            used += cidr.size();
+            entries += 1;
+            histogram[cidr.network_length() as usize] += 1;
+        }
+
+        let free = combiner.free_blocks(parent);
+
+        // the first of the shortest free blocks
+        let largest_free_block = free.iter().min_by_key(|cidr| cidr.network_length()).copied();
+
+        Ipv4Utilization {
+            parent: *parent,
+            used,
+            entries,
+            free_blocks: free.len(),
+            largest_free_block,
+            histogram,
+        }
+    }
+}
+
+impl Ipv4Utilization {
+    #[inline]
+    pub const fn parent(&self) -> Ipv4Cidr {
+        self.parent
+    }
+
+    /// Get the number of used addresses.
+    #[inline]
+    pub const fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Get the number of free addresses.
+    #[inline]
+    pub fn free(&self) -> u64 {
+        self.total() - self.used
+    }
+
+    /// Get the number of addresses of the parent CIDR.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.parent.size()
+    }
+
+    /// Get the percentage of used addresses, from `0.0` to `100.0`.
+    #[inline]
+    pub fn percentage(&self) -> f64 {
+        self.used as f64 * 100.0 / self.total() as f64
+    }
+
+    /// Get the number of used CIDRs in the parent CIDR.
+    #[inline]
+    pub const fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Get the number of aligned free blocks.
+    #[inline]
+    pub const fn free_blocks(&self) -> usize {
+        self.free_blocks
+    }
+
+    /// Get the largest aligned free block. If there are multiple ones, the lowest is returned.
+    #[inline]
+    pub const fn largest_free_block(&self) -> Option<Ipv4Cidr> {
+        self.largest_free_block
+    }
+
+    /// Get the fragmentation of the free space, from `0.0` to `1.0`.
+    #[inline]
+    pub fn fragmentation(&self) -> f64 {
+        match self.largest_free_block {
+            Some(cidr) => 1.0 - cidr.size() as f64 / self.free() as f64,
+            None => 0.0,
+        }
+    }
+
+    /// Get the numbers of used CIDRs, indexed by network length.
+    #[inline]
+    pub const fn histogram(&self) -> &[usize; 33] {
+        &self.histogram
+    }
+}
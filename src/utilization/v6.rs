@@ -0,0 +1,126 @@
+use cidr::Ipv6Cidr;
+
+use crate::{combiner::Ipv6CidrCombiner, Ipv6CidrSizeU128};
+
+/// The utilization of a parent IPv6 CIDR. Address counts saturate at `u128::MAX`, which only matters for `::/0`.
+#[derive(Debug, Clone)]
+pub struct Ipv6Utilization {
+    parent:             Ipv6Cidr,
+    used:               u128,
+    entries:            usize,
+    free_blocks:        usize,
+    largest_free_block: Option<Ipv6Cidr>,
+    histogram:          [usize; 129],
+}
+
+#[inline]
+fn size_u128_saturating(cidr: &Ipv6Cidr) -> u128 {
+    cidr.size_u128_checked().unwrap_or(u128::MAX)
+}
+
+impl Ipv6Utilization {
+    /// Compute the utilization of a parent CIDR by the CIDRs of a combiner. The CIDRs are clipped to the parent.
+    pub fn new(combiner: &Ipv6CidrCombiner, parent: &Ipv6Cidr) -> Ipv6Utilization {
+        let first = parent.first_address();
+        let last = parent.last_address();
+
+        let start = combiner.partition_point(|c| c.last_address() < first);
+
+        let mut used = 0u128;
+        let mut entries = 0;
+        let mut histogram = [0; 129];
+
+        for cidr in combiner[start..].iter().take_while(|c| c.first_address() <= last) {
+            let cidr = if cidr.network_length() < parent.network_length() { parent } else { cidr };
+
+            used = used.saturating_add(size_u128_saturating(cidr));
+            entries += 1;
+            histogram[cidr.network_length() as usize] += 1;
+        }
+
+        let free = combiner.free_blocks(parent);
+
+        // the first of the shortest free blocks
+        let largest_free_block = free.iter().min_by_key(|cidr| cidr.network_length()).copied();
+
+        Ipv6Utilization {
+            parent: *parent,
+            used,
+            entries,
+            free_blocks: free.len(),
+            largest_free_block,
+            histogram,
+        }
+    }
+}
+
+impl Ipv6Utilization {
+    #[inline]
+    pub const fn parent(&self) -> Ipv6Cidr {
+        self.parent
+    }
+
+    /// Get the number of used addresses.
+    #[inline]
+    pub const fn used_u128_saturating(&self) -> u128 {
+        self.used
+    }
+
+    /// Get the number of free addresses.
+    #[inline]
+    pub fn free_u128_saturating(&self) -> u128 {
+        match self.parent.size_u128_checked() {
+            Some(total) => total - self.used,
+            // `used` saturates, so a fully used `::/0` is recognized by having no free blocks
+            None if self.free_blocks == 0 => 0,
+            None => (u128::MAX - self.used).saturating_add(1),
+        }
+    }
+
+    /// Get the number of addresses of the parent CIDR.
+    #[inline]
+    pub fn total_u128_saturating(&self) -> u128 {
+        size_u128_saturating(&self.parent)
+    }
+
+    /// Get the percentage of used addresses, from `0.0` to `100.0`.
+    #[inline]
+    pub fn percentage(&self) -> f64 {
+        self.used as f64 * 100.0 / self.total_u128_saturating() as f64
+    }
+
+    /// Get the number of used CIDRs in the parent CIDR.
+    #[inline]
+    pub const fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Get the number of aligned free blocks.
+    #[inline]
+    pub const fn free_blocks(&self) -> usize {
+        self.free_blocks
+    }
+
+    /// Get the largest aligned free block. If there are multiple ones, the lowest is returned.
+    #[inline]
+    pub const fn largest_free_block(&self) -> Option<Ipv6Cidr> {
+        self.largest_free_block
+    }
+
+    /// Get the fragmentation of the free space, from `0.0` to `1.0`.
+    #[inline]
+    pub fn fragmentation(&self) -> f64 {
+        match self.largest_free_block {
+            Some(cidr) => {
+                1.0 - size_u128_saturating(&cidr) as f64 / self.free_u128_saturating() as f64
+            },
+            None => 0.0,
+        }
+    }
+
+    /// Get the numbers of used CIDRs, indexed by network length.
+    #[inline]
+    pub const fn histogram(&self) -> &[usize; 129] {
+        &self.histogram
+    }
+}
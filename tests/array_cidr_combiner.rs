@@ -0,0 +1,110 @@
+#![cfg(feature = "array-combiner")]
+
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::array_combiner::{ArrayIpv4CidrCombiner, ArrayIpv6CidrCombiner};
+
+#[test]
+fn ipv4_merge() {
+    let mut combiner = ArrayIpv4CidrCombiner::<2>::new();
+
+    combiner.try_push(Ipv4Cidr::from_str("192.168.1.100").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("192.168.1.101").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("192.168.1.102").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("192.168.1.103").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("192.168.1.101").unwrap()).unwrap();
+
+    assert_eq!(1, combiner.len());
+    assert_eq!("[192.168.1.100/30]", combiner.to_string());
+    assert_eq!(4, combiner.size());
+    assert!(combiner.contains(&Ipv4Addr::new(192, 168, 1, 102)));
+    assert!(!combiner.contains(&Ipv4Addr::new(192, 168, 1, 104)));
+
+    combiner.try_push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("10.1.0.0/16").unwrap()).unwrap();
+
+    assert_eq!("[10.0.0.0/8, 192.168.1.100/30]", combiner.to_string());
+}
+
+#[test]
+fn ipv4_capacity() {
+    let mut combiner = ArrayIpv4CidrCombiner::<2>::new();
+
+    combiner.try_push(Ipv4Cidr::from_str("10.0.0.0/24").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("10.0.2.0/24").unwrap()).unwrap();
+
+    let cidr = Ipv4Cidr::from_str("10.0.4.0/24").unwrap();
+
+    assert_eq!(cidr, combiner.try_push(cidr).unwrap_err().into_inner());
+    assert_eq!("[10.0.0.0/24, 10.0.2.0/24]", combiner.to_string());
+
+    // fits after merging 10.0.0.0/24 into 10.0.0.0/23 and then 10.0.2.0/24 into 10.0.0.0/22
+    combiner.try_push(Ipv4Cidr::from_str("10.0.1.0/24").unwrap()).unwrap();
+    combiner.try_push(Ipv4Cidr::from_str("10.0.3.0/24").unwrap()).unwrap();
+    combiner.try_push(cidr).unwrap();
+
+    assert_eq!("[10.0.0.0/22, 10.0.4.0/24]", combiner.to_string());
+
+    // replaces both entries
+    combiner.try_push(Ipv4Cidr::from_str("10.0.0.0/16").unwrap()).unwrap();
+
+    assert_eq!("[10.0.0.0/16]", combiner.to_string());
+
+    let mut empty = ArrayIpv4CidrCombiner::<0>::new();
+
+    assert!(empty.try_push(cidr).is_err());
+}
+
+#[cfg(feature = "combiner")]
+#[test]
+fn ipv4_same_as_combiner() {
+    use cidr_utils::combiner::Ipv4CidrCombiner;
+
+    let mut state = 0x2545_F491u32;
+
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        state
+    };
+
+    let mut array_combiner = ArrayIpv4CidrCombiner::<2048>::new();
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    for _ in 0..2000 {
+        let bits = 22 + (next() % 11) as u8;
+        let addr = 0x0A00_0000 | (next() & 0xFFFF) << 6;
+        let mask = u32::MAX << (32 - bits);
+
+        let cidr = Ipv4Cidr::new(Ipv4Addr::from(addr & mask), bits).unwrap();
+
+        array_combiner.try_push(cidr).unwrap();
+        combiner.push(cidr);
+
+        assert_eq!(combiner.as_slice(), array_combiner.as_slice());
+    }
+}
+
+#[test]
+fn ipv6_merge() {
+    let mut combiner = ArrayIpv6CidrCombiner::<1>::new();
+
+    combiner.try_push(Ipv6Cidr::from_str("::/1").unwrap()).unwrap();
+    combiner.try_push(Ipv6Cidr::from_str("8000::/1").unwrap()).unwrap();
+
+    assert_eq!("[::/0]", combiner.to_string());
+    assert_eq!(u128::MAX, combiner.size_u128_saturating());
+    assert!(combiner.contains(&Ipv6Addr::LOCALHOST));
+
+    combiner.clear();
+
+    combiner.try_push(Ipv6Cidr::from_str("2001:db8::/32").unwrap()).unwrap();
+
+    assert!(combiner.try_push(Ipv6Cidr::from_str("2001:dba::/32").unwrap()).is_err());
+    assert_eq!(1, combiner.len());
+    assert_eq!(1, combiner.capacity());
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "diff")]
+
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    diff::CidrChangeKind,
+};
+
+fn ipv4_combiner(cidrs: &[&str]) -> Ipv4CidrCombiner {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    for cidr in cidrs {
+        combiner.push(Ipv4Cidr::from_str(cidr).unwrap());
+    }
+
+    combiner
+}
+
+#[test]
+fn ipv4_diff() {
+    let old = ipv4_combiner(&[
+        "10.0.0.0/24",
+        "10.1.0.0/24",
+        "10.2.0.0/24",
+        "10.3.0.0/24",
+        "10.3.2.0/24",
+        "10.4.0.0/24",
+        "10.5.0.0/24",
+    ]);
+    let new = ipv4_combiner(&[
+        "10.0.0.0/24",
+        "10.1.0.0/23",
+        "10.2.0.0/25",
+        "10.3.0.0/22",
+        "10.4.0.0/26",
+        "10.4.0.128/26",
+        "10.6.0.0/24",
+    ]);
+
+    let diff = old.diff(&new);
+
+    assert_eq!("[10.1.1.0/24, 10.3.1.0/24, 10.3.3.0/24, 10.6.0.0/24]", diff.added().to_string());
+    assert_eq!(
+        "[10.2.0.128/25, 10.4.0.64/26, 10.4.0.192/26, 10.5.0.0/24]",
+        diff.removed().to_string()
+    );
+
+    let kinds: Vec<CidrChangeKind> = diff.changes().iter().map(|c| c.kind).collect();
+
+    assert_eq!(
+        vec![
+            CidrChangeKind::Unchanged,
+            CidrChangeKind::Widened,
+            CidrChangeKind::Narrowed,
+            CidrChangeKind::Merged,
+            CidrChangeKind::Split,
+            CidrChangeKind::Removed,
+            CidrChangeKind::Added,
+        ],
+        kinds
+    );
+
+    assert_eq!(2, diff.changes()[3].old.len());
+    assert_eq!(2, diff.changes()[4].new.len());
+
+    assert_eq!(
+        "widened 10.1.0.0/24 -> 10.1.0.0/23\nnarrowed 10.2.0.0/24 -> 10.2.0.0/25\nmerged \
+         10.3.0.0/24, 10.3.2.0/24 -> 10.3.0.0/22\nsplit 10.4.0.0/24 -> 10.4.0.0/26, \
+         10.4.0.128/26\nremoved 10.5.0.0/24\nadded 10.6.0.0/24\n",
+        diff.to_string()
+    );
+
+    assert!(!diff.is_empty());
+    assert!(old.diff(&old).is_empty());
+    assert_eq!("", old.diff(&old).to_string());
+}
+
+#[test]
+fn ipv4_diff_empty() {
+    let old = Ipv4CidrCombiner::new();
+    let new = ipv4_combiner(&["192.168.0.0/16"]);
+
+    let diff = old.diff(&new);
+
+    assert_eq!("[192.168.0.0/16]", diff.added().to_string());
+    assert!(diff.removed().is_empty());
+    assert_eq!(CidrChangeKind::Added, diff.changes()[0].kind);
+
+    let diff = new.diff(&old);
+
+    assert_eq!("[192.168.0.0/16]", diff.removed().to_string());
+    assert_eq!(CidrChangeKind::Removed, diff.changes()[0].kind);
+}
+
+#[test]
+fn ipv6_diff() {
+    let mut old = Ipv6CidrCombiner::new();
+    let mut new = Ipv6CidrCombiner::new();
+
+    old.push(Ipv6Cidr::from_str("2001:db8::/48").unwrap());
+    old.push(Ipv6Cidr::from_str("2001:db8:2::/48").unwrap());
+    new.push(Ipv6Cidr::from_str("2001:db8::/46").unwrap());
+
+    let diff = old.diff(&new);
+
+    assert_eq!("[2001:db8:1::/48, 2001:db8:3::/48]", diff.added().to_string());
+    assert!(diff.removed().is_empty());
+    assert_eq!(1, diff.changes().len());
+    assert_eq!(CidrChangeKind::Merged, diff.changes()[0].kind);
+}
+
+#[test]
+fn ipv4_diff_matches_subtract() {
+    let mut state = 0x2545_F491_u32;
+
+    let mut random = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        state
+    };
+
+    for _ in 0..500 {
+        let mut old = Ipv4CidrCombiner::new();
+        let mut new = Ipv4CidrCombiner::new();
+
+        for combiner in [&mut old, &mut new] {
+            for _ in 0..random() % 12 {
+                let bits = 24 + (random() % 7) as u8;
+                let address = 0x0A00_0000 | (random() & 0xFF & !(u32::MAX >> bits));
+
+                combiner.push(Ipv4Cidr::new(address.into(), bits).unwrap());
+            }
+        }
+
+        let mut added = new.clone();
+        let mut removed = old.clone();
+
+        for cidr in old.iter() {
+            added.subtract(cidr);
+        }
+
+        for cidr in new.iter() {
+            removed.subtract(cidr);
+        }
+
+        let diff = old.diff(&new);
+
+        assert_eq!(added.to_string(), diff.added().to_string(), "{old} -> {new}");
+        assert_eq!(removed.to_string(), diff.removed().to_string(), "{old} -> {new}");
+    }
+}
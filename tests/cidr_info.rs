@@ -0,0 +1,56 @@
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{Ipv4CidrInfo, Ipv6CidrInfo};
+
+#[test]
+fn ipv4_info() {
+    let cidr = Ipv4Cidr::from_str("10.0.0.0/8").unwrap();
+
+    assert_eq!(Ipv4Addr::new(255, 0, 0, 0), cidr.netmask());
+    assert_eq!(Ipv4Addr::new(0, 255, 255, 255), cidr.wildcard_mask());
+    assert_eq!(Some(Ipv4Addr::new(10, 255, 255, 255)), cidr.broadcast());
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 1), cidr.first_host());
+    assert_eq!(Ipv4Addr::new(10, 255, 255, 254), cidr.last_host());
+    assert_eq!(16777214, cidr.host_count());
+
+    let cidr = Ipv4Cidr::from_str("0.0.0.0/0").unwrap();
+
+    assert_eq!(Ipv4Addr::UNSPECIFIED, cidr.netmask());
+    assert_eq!(Ipv4Addr::BROADCAST, cidr.wildcard_mask());
+    assert_eq!(Some(Ipv4Addr::BROADCAST), cidr.broadcast());
+    assert_eq!(4294967294, cidr.host_count());
+
+    let cidr = Ipv4Cidr::from_str("192.0.2.10/31").unwrap();
+
+    assert_eq!(None, cidr.broadcast());
+    assert_eq!(Ipv4Addr::new(192, 0, 2, 10), cidr.first_host());
+    assert_eq!(Ipv4Addr::new(192, 0, 2, 11), cidr.last_host());
+    assert_eq!(2, cidr.host_count());
+
+    let cidr = Ipv4Cidr::from_str("192.0.2.10/32").unwrap();
+
+    assert_eq!(Ipv4Addr::BROADCAST, cidr.netmask());
+    assert_eq!(Ipv4Addr::UNSPECIFIED, cidr.wildcard_mask());
+    assert_eq!(None, cidr.broadcast());
+    assert_eq!(Ipv4Addr::new(192, 0, 2, 10), cidr.first_host());
+    assert_eq!(Ipv4Addr::new(192, 0, 2, 10), cidr.last_host());
+    assert_eq!(1, cidr.host_count());
+}
+
+#[test]
+fn ipv6_info() {
+    let cidr = Ipv6Cidr::from_str("::/0").unwrap();
+
+    assert_eq!(Ipv6Addr::UNSPECIFIED, cidr.netmask());
+    assert_eq!(Ipv6Addr::from(u128::MAX), cidr.last_host());
+    assert_eq!(128, cidr.interface_identifier_bits());
+
+    let cidr = Ipv6Cidr::from_str("2001:db8::1/128").unwrap();
+
+    assert_eq!(Ipv6Addr::from(u128::MAX), cidr.netmask());
+    assert_eq!(Ipv6Addr::UNSPECIFIED, cidr.wildcard_mask());
+    assert_eq!(cidr.first_host(), cidr.last_host());
+    assert_eq!(0, cidr.interface_identifier_bits());
+}
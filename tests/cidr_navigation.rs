@@ -0,0 +1,67 @@
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{Ipv4CidrNavigation, Ipv6CidrNavigation};
+
+fn v4(s: &str) -> Ipv4Cidr {
+    Ipv4Cidr::from_str(s).unwrap()
+}
+
+fn v6(s: &str) -> Ipv6Cidr {
+    Ipv6Cidr::from_str(s).unwrap()
+}
+
+#[test]
+fn ipv4_bounds() {
+    let all = v4("0.0.0.0/0");
+
+    assert_eq!(None, all.parent());
+    assert_eq!(None, all.sibling());
+    assert_eq!(None, all.next_block());
+    assert_eq!(None, all.prev_block());
+    assert_eq!(Some((v4("0.0.0.0/1"), v4("128.0.0.0/1"))), all.children());
+    assert_eq!(Some(all), all.supernet(0));
+
+    let host = v4("255.255.255.255/32");
+
+    assert_eq!(None, host.children());
+    assert_eq!(None, host.next_block());
+    assert_eq!(None, host.supernet(33));
+    assert_eq!(Some(v4("255.255.255.254/32")), host.prev_block());
+    assert_eq!(Some(v4("255.255.255.254/32")), host.sibling());
+    assert_eq!(Some(v4("255.255.255.254/31")), host.parent());
+
+    assert_eq!(None, v4("0.0.0.0/8").prev_block());
+    assert_eq!(Some(v4("128.0.0.0/1")), v4("0.0.0.0/1").next_block());
+    assert_eq!(None, v4("10.0.0.0/8").supernet(9));
+}
+
+#[test]
+fn ipv4_common_supernet() {
+    assert_eq!(v4("10.0.0.0/8"), v4("10.0.0.0/8").common_supernet(&v4("10.1.2.0/24")));
+    assert_eq!(v4("10.0.0.0/8"), v4("10.1.2.0/24").common_supernet(&v4("10.0.0.0/8")));
+    assert_eq!(v4("0.0.0.0/0"), v4("1.0.0.0/8").common_supernet(&v4("255.0.0.0/8")));
+    assert_eq!(v4("192.0.2.4/32"), v4("192.0.2.4").common_supernet(&v4("192.0.2.4")));
+    assert_eq!(v4("192.0.2.4/31"), v4("192.0.2.4").common_supernet(&v4("192.0.2.5")));
+}
+
+#[test]
+fn ipv6_bounds() {
+    let all = v6("::/0");
+
+    assert_eq!(None, all.parent());
+    assert_eq!(None, all.sibling());
+    assert_eq!(None, all.next_block());
+    assert_eq!(None, all.prev_block());
+    assert_eq!(Some((v6("::/1"), v6("8000::/1"))), all.children());
+
+    let host = v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128");
+
+    assert_eq!(None, host.children());
+    assert_eq!(None, host.next_block());
+    assert_eq!(Some(v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/128")), host.prev_block());
+
+    assert_eq!(None, v6("::/64").prev_block());
+    assert_eq!(Some(v6("2001:db8::/32")), v6("2001:db8:1::/48").supernet(32));
+    assert_eq!(v6("2001:db8::/46"), v6("2001:db8::/48").common_supernet(&v6("2001:db8:3::/48")));
+}
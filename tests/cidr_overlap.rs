@@ -0,0 +1,81 @@
+#![cfg(feature = "overlap")]
+
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    overlap::{Ipv4OverlapAnalyzer, Ipv6OverlapAnalyzer},
+};
+
+fn ipv4_combiner(cidrs: &[&str]) -> Ipv4CidrCombiner {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    for cidr in cidrs {
+        combiner.push(Ipv4Cidr::from_str(cidr).unwrap());
+    }
+
+    combiner
+}
+
+#[test]
+fn ipv4_overlaps() {
+    let mut analyzer = Ipv4OverlapAnalyzer::new();
+
+    analyzer.insert("prod", ipv4_combiner(&["10.0.0.0/8"]));
+    analyzer.insert("staging", ipv4_combiner(&["10.1.0.0/16", "172.16.0.0/16"]));
+    analyzer.insert("dev", ipv4_combiner(&["10.1.2.0/24", "10.2.0.0/24", "192.168.0.0/24"]));
+    analyzer.insert("office", ipv4_combiner(&["172.16.0.0/16", "192.168.1.0/24"]));
+
+    assert_eq!(4, analyzer.len());
+
+    let overlaps: Vec<(&str, &str, String)> = analyzer
+        .analyze()
+        .into_iter()
+        .map(|o| (*o.labels.0, *o.labels.1, o.cidrs.to_string()))
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("prod", "staging", "[10.1.0.0/16]".to_string()),
+            ("prod", "dev", "[10.1.2.0/24, 10.2.0.0/24]".to_string()),
+            ("staging", "dev", "[10.1.2.0/24]".to_string()),
+            ("staging", "office", "[172.16.0.0/16]".to_string()),
+        ],
+        overlaps
+    );
+}
+
+#[test]
+fn ipv4_disjoint() {
+    let mut analyzer = Ipv4OverlapAnalyzer::new();
+
+    assert!(analyzer.analyze().is_empty());
+
+    analyzer.insert(1, ipv4_combiner(&["10.0.0.0/24"]));
+    analyzer.insert(2, ipv4_combiner(&["10.0.1.0/24"]));
+    analyzer.insert(3, Ipv4CidrCombiner::new());
+
+    assert!(analyzer.analyze().is_empty());
+}
+
+#[test]
+fn ipv6_overlaps() {
+    let mut a = Ipv6CidrCombiner::new();
+    let mut b = Ipv6CidrCombiner::new();
+
+    a.push(Ipv6Cidr::from_str("2001:db8::/48").unwrap());
+    b.push(Ipv6Cidr::from_str("2001:db8::/56").unwrap());
+    b.push(Ipv6Cidr::from_str("2001:db8:0:ff00::/56").unwrap());
+    b.push(Ipv6Cidr::from_str("2001:db8:1::/48").unwrap());
+
+    let mut analyzer = Ipv6OverlapAnalyzer::new();
+
+    analyzer.insert("a", a);
+    analyzer.insert("b", b);
+
+    let overlaps = analyzer.analyze();
+
+    assert_eq!(1, overlaps.len());
+    assert_eq!("[2001:db8::/56, 2001:db8:0:ff00::/56]", overlaps[0].cidrs.to_string());
+}
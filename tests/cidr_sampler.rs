@@ -0,0 +1,141 @@
+#![cfg(feature = "sampler")]
+
+use core::str::FromStr;
+use std::{
+    collections::BTreeSet,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    sampler::{Ipv4CidrSampler, Ipv6CidrSampler},
+};
+
+#[test]
+fn ipv4_sample() {
+    let cidr = Ipv4Cidr::from_str("192.168.0.0/24").unwrap();
+
+    let addrs = Ipv4CidrSampler::sample(&cidr, 10, 42).unwrap();
+
+    assert_eq!(10, addrs.len());
+    assert!(addrs.windows(2).all(|w| w[0] < w[1]));
+    assert!(addrs.iter().all(|addr| cidr.contains(addr)));
+
+    assert_eq!(addrs, Ipv4CidrSampler::sample(&cidr, 10, 42).unwrap());
+    assert_ne!(addrs, Ipv4CidrSampler::sample(&cidr, 10, 43).unwrap());
+
+    let all = Ipv4CidrSampler::sample(&cidr, 256, 7).unwrap();
+
+    assert_eq!(256, all.len());
+    assert!(all.iter().enumerate().all(|(i, addr)| addr.octets()[3] as usize == i));
+
+    assert!(Ipv4CidrSampler::sample(&cidr, 257, 7).is_none());
+    assert_eq!(Some(Vec::new()), Ipv4CidrSampler::sample(&cidr, 0, 7));
+
+    let single = Ipv4Cidr::from_str("10.0.0.1/32").unwrap();
+
+    assert_eq!(Some(vec![Ipv4Addr::new(10, 0, 0, 1)]), Ipv4CidrSampler::sample(&single, 1, 0));
+
+    let whole = Ipv4Cidr::from_str("0.0.0.0/0").unwrap();
+
+    assert_eq!(1000, Ipv4CidrSampler::sample(&whole, 1000, 0).unwrap().len());
+}
+
+#[test]
+fn ipv4_sample_is_uniform() {
+    let cidr = Ipv4Cidr::from_str("10.0.0.0/29").unwrap();
+
+    let mut counts = [0usize; 8];
+
+    for seed in 0..8000 {
+        for addr in Ipv4CidrSampler::sample(&cidr, 2, seed).unwrap() {
+            counts[addr.octets()[3] as usize] += 1;
+        }
+    }
+
+    // each address is expected 2000 times
+    assert!(counts.iter().all(|&count| (1800..2200).contains(&count)), "{counts:?}");
+}
+
+#[test]
+fn ipv4_sample_combiner() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/30").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.1.0/31").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.0.1/32").unwrap());
+
+    assert_eq!(
+        Some(vec![
+            Ipv4Addr::new(10, 0, 0, 0),
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(10, 0, 0, 2),
+            Ipv4Addr::new(10, 0, 0, 3),
+            Ipv4Addr::new(10, 0, 1, 0),
+            Ipv4Addr::new(10, 0, 1, 1),
+            Ipv4Addr::new(192, 168, 0, 1),
+        ]),
+        combiner.sample(7, 1)
+    );
+
+    for seed in 0..100 {
+        let addrs = combiner.sample(3, seed).unwrap();
+
+        assert_eq!(3, addrs.iter().collect::<BTreeSet<_>>().len());
+        assert!(addrs.iter().all(|addr| combiner.contains(addr)));
+    }
+
+    assert!(combiner.sample(8, 1).is_none());
+    assert!(Ipv4CidrCombiner::new().sample(1, 1).is_none());
+    assert_eq!(Some(Vec::new()), Ipv4CidrCombiner::new().sample(0, 1));
+}
+
+#[test]
+fn ipv6_sample() {
+    let cidr = Ipv6Cidr::from_str("2001:db8:1::/48").unwrap();
+
+    let addrs = Ipv6CidrSampler::sample(&cidr, 100, 42).unwrap();
+
+    assert_eq!(100, addrs.len());
+    assert!(addrs.windows(2).all(|w| w[0] < w[1]));
+    assert!(addrs.iter().all(|addr| cidr.contains(addr)));
+    assert_eq!(addrs, Ipv6CidrSampler::sample(&cidr, 100, 42).unwrap());
+
+    let whole = Ipv6Cidr::from_str("::/0").unwrap();
+
+    assert_eq!(100, Ipv6CidrSampler::sample(&whole, 100, 42).unwrap().len());
+
+    let single = Ipv6Cidr::from_str("2001:db8::1/128").unwrap();
+
+    assert_eq!(
+        Some(vec![Ipv6Addr::from_str("2001:db8::1").unwrap()]),
+        Ipv6CidrSampler::sample(&single, 1, 0)
+    );
+    assert!(Ipv6CidrSampler::sample(&single, 2, 0).is_none());
+}
+
+#[test]
+fn ipv6_sample_combiner() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/127").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:1::/64").unwrap());
+
+    let addrs = combiner.sample(2, 5).unwrap();
+
+    assert!(addrs.iter().all(|addr| combiner.contains(addr)));
+
+    let first = Ipv6Addr::from_str("2001:db8::").unwrap();
+
+    // the two addresses of the /127 are picked in about 2 / 2^64 of the cases
+    let hits = (0..1000).filter(|&seed| combiner.sample(1, seed).unwrap()[0] == first).count();
+
+    assert_eq!(0, hits);
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("::/0").unwrap());
+
+    assert_eq!(10, combiner.sample(10, 0).unwrap().len());
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    env, fs,
+    io::{Read, Write},
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cidr-utils"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn aggregate() {
+    let output = run(
+        &["aggregate"],
+        "10.0.0.0/25\n10.0.0.128/25 # a comment\n2001:db8::/33, 2001:db8:8000::/33\n\n",
+    );
+
+    assert!(output.status.success());
+    assert_eq!("10.0.0.0/24\n2001:db8::/32\n", stdout(&output));
+
+    let path = env::temp_dir().join(format!("cidr-utils-aggregate-{}.txt", std::process::id()));
+
+    fs::write(&path, "192.168.0.0/24 192.168.1.0/24\n10.0.0.1\n").unwrap();
+
+    let output = run(&["aggregate", path.to_str().unwrap()], "");
+
+    fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!("10.0.0.1\n192.168.0.0/23\n", stdout(&output));
+}
+
+#[test]
+fn contains() {
+    let output = run(&["contains", "10.0.0.0/8,2001:db8::/32", "10.1.2.3", "2001:db8::1"], "");
+
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("10.1.2.3\tyes\n2001:db8::1\tyes\n", stdout(&output));
+
+    let output = run(&["contains", "10.0.0.0/8", "10.1.2.3", "192.168.0.1"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("10.1.2.3\tyes\n192.168.0.1\tno\n", stdout(&output));
+    assert_eq!("", stderr(&output));
+
+    let output = run(&["contains", "@-", "192.168.0.1"], "192.168.0.0/16\n");
+
+    assert_eq!(Some(0), output.status.code());
+}
+
+#[test]
+fn split() {
+    let output = run(&["split", "192.168.0.0/24", "--count", "4"], "");
+
+    assert!(output.status.success());
+    assert_eq!(
+        "192.168.0.0/26\n192.168.0.64/26\n192.168.0.128/26\n192.168.0.192/26\n",
+        stdout(&output)
+    );
+
+    let output = run(&["split", "2001:db8::/32", "--bits", "34"], "");
+
+    assert!(output.status.success());
+    assert_eq!(
+        "2001:db8::/34\n2001:db8:4000::/34\n2001:db8:8000::/34\n2001:db8:c000::/34\n",
+        stdout(&output)
+    );
+
+    let output = run(&["split", "192.168.0.0/24", "--bits", "16"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(
+        "cidr-utils: cannot divide 192.168.0.0/24 into subnetworks of /16\n",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn info() {
+    let output = run(&["info", "192.168.1.0/24"], "");
+
+    assert!(output.status.success());
+    assert_eq!(
+        "Network:    192.168.1.0/24\nNetmask:    255.255.255.0\nWildcard:   0.0.0.255\nBroadcast:  \
+         192.168.1.255\nFirst host: 192.168.1.1\nLast host:  192.168.1.254\nHosts:      254\nSize:       \
+         256\n",
+        stdout(&output)
+    );
+
+    let output = run(&["info", "2001:db8::/120"], "");
+
+    assert!(output.status.success());
+    assert_eq!(
+        "Network:    2001:db8::/120\nNetmask:    \
+         ffff:ffff:ffff:ffff:ffff:ffff:ffff:ff00\nWildcard:   ::ff\nFirst host: 2001:db8::\nLast \
+         host:  2001:db8::ff\nSize:       256 (2^8)\n",
+        stdout(&output)
+    );
+
+    let output = run(&["info", "::/0"], "");
+
+    assert!(output.status.success());
+    assert!(stdout(&output).ends_with("Size:       2^128\n"));
+}
+
+#[test]
+fn errors() {
+    let output = run(&["frobnicate"], "");
+
+    assert_eq!(Some(2), output.status.code());
+    assert_eq!(
+        "cidr-utils: unknown command `frobnicate`\nTry `cidr-utils --help` for more information.\n",
+        stderr(&output)
+    );
+    assert_eq!("", stdout(&output));
+
+    let output = run(&["split", "192.168.0.0/24"], "");
+
+    assert_eq!(Some(2), output.status.code());
+
+    let output = run(&["split", "192.168.0.0/24", "--pieces", "4"], "");
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(stderr(&output).starts_with("cidr-utils: unknown option `--pieces`\n"));
+
+    let output = run(&["contains", "10.0.0.0/8"], "");
+
+    assert_eq!(Some(2), output.status.code());
+
+    let output = run(&["info", "10.0.0.0/33"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("cidr-utils: invalid CIDR `10.0.0.0/33`\n", stderr(&output));
+
+    let output = run(&["contains", "10.0.0.0/8", "10.0.0.256"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("cidr-utils: invalid address `10.0.0.256`\n", stderr(&output));
+
+    let output = run(&["aggregate", "/nonexistent/cidrs.txt"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(stderr(&output).starts_with("cidr-utils: /nonexistent/cidrs.txt: "));
+
+    let output = run(&["--help"], "");
+
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Usage: cidr-utils <COMMAND> [ARGS]\n"));
+    assert!(stdout(&output).contains("\nExit status:\n"));
+}
+
+#[test]
+fn closed_stdout() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cidr-utils"))
+        .args(["expand", "10.0.0.0/8"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = child.stdout.take().unwrap();
+    let mut head = [0; 64];
+
+    stdout.read_exact(&mut head).unwrap();
+
+    drop(stdout);
+
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!("", stderr(&output));
+}
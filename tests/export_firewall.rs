@@ -0,0 +1,105 @@
+#![cfg(feature = "export")]
+
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    export::{IpsetRestore, Iptables, NftFamily, NftSet, PfTable},
+};
+
+#[test]
+fn ipset_restore() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("::1").unwrap());
+
+    assert_eq!(
+        "create v6-block hash:net family inet6 -exist\nadd v6-block ::1/128 -exist\nadd v6-block \
+         2001:db8::/32 -exist\n",
+        IpsetRestore::new_ipv6(&combiner, "v6-block").unwrap().to_string()
+    );
+
+    assert_eq!(
+        "add v6-block ::1/128 -exist\nadd v6-block 2001:db8::/32 -exist\n",
+        IpsetRestore::new_ipv6(&combiner, "v6-block").unwrap().create(false).to_string()
+    );
+
+    assert!(IpsetRestore::new_ipv6(&combiner, "").is_none());
+    assert!(IpsetRestore::new_ipv6(&combiner, "my set").is_none());
+    assert!(IpsetRestore::new_ipv6(&combiner, "-exist").is_none());
+    assert!(IpsetRestore::new_ipv6(&combiner, "a234567890123456789012345678901").is_some());
+    assert!(IpsetRestore::new_ipv6(&combiner, "a2345678901234567890123456789012").is_none());
+}
+
+#[test]
+fn nft_set() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    assert_eq!(
+        "add set ip6 filter empty { type ipv6_addr; flags interval; }\n",
+        NftSet::new_ipv6(&combiner, "filter", "empty").unwrap().family(NftFamily::Ip6).to_string()
+    );
+
+    combiner.push(Ipv6Cidr::from_str("fc00::/7").unwrap());
+
+    assert_eq!(
+        "add set inet filter ula { type ipv6_addr; flags interval; }\nadd element inet filter ula \
+         { fc00::/7 }\n",
+        NftSet::new_ipv6(&combiner, "filter", "ula").unwrap().to_string()
+    );
+
+    assert!(NftSet::new_ipv6(&combiner, "filter", "1set").is_none());
+    assert!(NftSet::new_ipv6(&combiner, "my filter", "set").is_none());
+    assert!(NftSet::new_ipv6(&combiner, "filter", "set;").is_none());
+}
+
+#[test]
+fn iptables() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.1").unwrap());
+
+    assert_eq!(
+        "iptables -A 'OUT$PUT' -d 10.0.0.0/8 -j REJECT\niptables -A 'OUT$PUT' -d 192.168.1.1/32 \
+         -j REJECT\n",
+        Iptables::new_ipv4(&combiner, "OUT$PUT", "REJECT").unwrap().destination(true).to_string()
+    );
+
+    assert!(Iptables::new_ipv4(&combiner, "-INPUT", "DROP").is_none());
+    assert!(Iptables::new_ipv4(&combiner, "INPUT", "!DROP").is_none());
+    assert!(Iptables::new_ipv4(&combiner, "IN'PUT", "DROP").is_none());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+
+    assert_eq!(
+        "ip6tables -A INPUT -s 2001:db8::/32 -j DROP\n",
+        Iptables::new_ipv6(&combiner, "INPUT", "DROP").unwrap().to_string()
+    );
+}
+
+#[test]
+fn pf_table() {
+    let combiner = Ipv4CidrCombiner::new();
+
+    assert_eq!(
+        "table <empty> { }\n",
+        PfTable::new_ipv4(&combiner, "empty").unwrap().persist(false).to_string()
+    );
+
+    assert!(PfTable::new_ipv4(&combiner, "a>b").is_none());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fe80::/10").unwrap());
+
+    assert_eq!(
+        "table <v6> persist { 2001:db8::/32, fe80::/10 }\n",
+        PfTable::new_ipv6(&combiner, "v6").unwrap().to_string()
+    );
+}
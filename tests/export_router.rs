@@ -0,0 +1,188 @@
+#![cfg(feature = "export")]
+
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    export::{
+        BirdPrefixSet, CiscoPrefixList, JuniperPrefixList, JuniperRouteFilter, PrefixListAction,
+    },
+};
+
+#[test]
+fn cisco_prefix_list() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+    combiner.push(Ipv4Cidr::from_str("172.16.0.0/12").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+
+    assert_eq!(
+        "ip prefix-list BOGONS seq 100 deny 10.0.0.0/8 ge 16 le 32\nip prefix-list BOGONS seq 110 \
+         deny 172.16.0.0/12 ge 16 le 32\nip prefix-list BOGONS seq 120 deny 192.168.1.0/24 le 32\n",
+        CiscoPrefixList::new_ipv4(&combiner, "BOGONS")
+            .unwrap()
+            .action(PrefixListAction::Deny)
+            .sequence(100, 10)
+            .ge(16)
+            .unwrap()
+            .le(40)
+            .unwrap()
+            .to_string()
+    );
+
+    assert_eq!(
+        "ip prefix-list BOGONS permit 10.0.0.0/8 ge 24 le 24\nip prefix-list BOGONS permit \
+         172.16.0.0/12 ge 24 le 24\nip prefix-list BOGONS permit 192.168.1.0/24\n",
+        CiscoPrefixList::new_ipv4(&combiner, "BOGONS")
+            .unwrap()
+            .no_sequence()
+            .ge(24)
+            .unwrap()
+            .le(24)
+            .unwrap()
+            .to_string()
+    );
+
+    assert!(CiscoPrefixList::new_ipv4(&combiner, "BOGONS")
+        .unwrap()
+        .ge(24)
+        .unwrap()
+        .le(20)
+        .is_none());
+    assert!(CiscoPrefixList::new_ipv4(&combiner, "BOGONS").unwrap().le(4).unwrap().ge(8).is_none());
+
+    assert!(CiscoPrefixList::new_ipv4(&combiner, "TWO WORDS").is_none());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+
+    assert_eq!(
+        "ipv6 prefix-list V6 seq 5 permit 2001:db8::/32 le 48\n",
+        CiscoPrefixList::new_ipv6(&combiner, "V6").unwrap().le(48).unwrap().to_string()
+    );
+
+    assert_eq!(
+        "ipv6 prefix-list V6 seq 5 permit 2001:db8::/32 le 128\n",
+        CiscoPrefixList::new_ipv6(&combiner, "V6").unwrap().ge(16).unwrap().to_string()
+    );
+}
+
+#[test]
+fn cisco_prefix_list_ge_reached() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.1.0.0/16").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.0.1/32").unwrap());
+
+    // "/24 or longer" keeps matching the longer prefixes of an entry which is already longer than /24
+    assert_eq!(
+        "ip prefix-list LONG seq 5 permit 10.0.0.0/25 le 32\nip prefix-list LONG seq 10 permit \
+         10.1.0.0/16 ge 24\nip prefix-list LONG seq 15 permit 192.168.0.1/32\n",
+        CiscoPrefixList::new_ipv4(&combiner, "LONG").unwrap().ge(24).unwrap().to_string()
+    );
+
+    assert_eq!(
+        "ip prefix-list LONG seq 5 permit 10.0.0.0/25 le 28\nip prefix-list LONG seq 10 permit \
+         10.1.0.0/16 le 28\nip prefix-list LONG seq 15 permit 192.168.0.1/32\n",
+        CiscoPrefixList::new_ipv4(&combiner, "LONG")
+            .unwrap()
+            .ge(8)
+            .unwrap()
+            .le(28)
+            .unwrap()
+            .to_string()
+    );
+
+    assert!(CiscoPrefixList::new_ipv4(&combiner, "LONG").unwrap().ge(8).unwrap().le(4).is_none());
+}
+
+#[test]
+fn cisco_prefix_list_empty() {
+    let combiner = Ipv4CidrCombiner::new();
+
+    assert_eq!(
+        "",
+        CiscoPrefixList::new_ipv4(&combiner, "EMPTY").unwrap().le(24).unwrap().to_string()
+    );
+
+    let combiner = Ipv6CidrCombiner::new();
+
+    assert_eq!("", CiscoPrefixList::new_ipv6(&combiner, "EMPTY").unwrap().to_string());
+}
+
+#[test]
+fn juniper() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:1::/48").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fc00::/7").unwrap());
+
+    assert_eq!(
+        "prefix-list private-v6 {\n    2001:db8::/32;\n    fc00::/7;\n}\n",
+        JuniperPrefixList::new_ipv6(&combiner, "private-v6").unwrap().to_string()
+    );
+
+    assert!(JuniperPrefixList::new_ipv6(&combiner, "a{b").is_none());
+
+    assert_eq!(
+        "route-filter 2001:db8::/32 exact;\nroute-filter fc00::/7 exact;\n",
+        JuniperRouteFilter::new_ipv6(&combiner).to_string()
+    );
+
+    assert_eq!(
+        "route-filter 2001:db8::/32 upto /128;\nroute-filter fc00::/7 upto /128;\n",
+        JuniperRouteFilter::new_ipv6(&combiner).max_length(200).to_string()
+    );
+}
+
+#[test]
+fn juniper_empty() {
+    let combiner = Ipv4CidrCombiner::new();
+
+    assert_eq!(
+        "prefix-list empty {\n}\n",
+        JuniperPrefixList::new_ipv4(&combiner, "empty").unwrap().to_string()
+    );
+
+    assert_eq!("", JuniperRouteFilter::new_ipv4(&combiner).max_length(24).to_string());
+
+    let combiner = Ipv6CidrCombiner::new();
+
+    assert_eq!(
+        "prefix-list empty {\n}\n",
+        JuniperPrefixList::new_ipv6(&combiner, "empty").unwrap().to_string()
+    );
+
+    assert_eq!("", JuniperRouteFilter::new_ipv6(&combiner).to_string());
+}
+
+#[test]
+fn bird_prefix_set() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:1::/48").unwrap());
+
+    assert_eq!(
+        "define v6_customers = [ 2001:db8::/32 ];\n",
+        BirdPrefixSet::new_ipv6(&combiner, "v6_customers").unwrap().max_length(32).to_string()
+    );
+
+    assert_eq!(
+        "define v6_customers = [ 2001:db8::/32{32,48} ];\n",
+        BirdPrefixSet::new_ipv6(&combiner, "v6_customers").unwrap().max_length(48).to_string()
+    );
+
+    assert!(BirdPrefixSet::new_ipv6(&combiner, "v6-customers").is_none());
+}
+
+#[test]
+fn bird_prefix_set_empty() {
+    assert!(BirdPrefixSet::new_ipv4(&Ipv4CidrCombiner::new(), "empty").is_none());
+    assert!(BirdPrefixSet::new_ipv6(&Ipv6CidrCombiner::new(), "empty").is_none());
+}
@@ -1,9 +1,9 @@
 #![cfg(feature = "combiner")]
 
-use core::str::FromStr;
+use core::{net::Ipv4Addr, str::FromStr};
 
 use cidr::Ipv4Cidr;
-use cidr_utils::combiner::Ipv4CidrCombiner;
+use cidr_utils::combiner::{Ipv4CidrCombiner, MergePolicy};
 
 #[test]
 fn push() {
@@ -124,3 +124,347 @@ fn simple_test() {
     assert_eq!(1, combiner.len());
     assert_eq!(Ipv4Cidr::from_str("192.168.1.100/30").unwrap(), combiner[0]);
 }
+
+#[test]
+fn aggregate_to() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.1.0/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.2.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.0.0/24").unwrap());
+
+    assert_eq!(Some(0), combiner.aggregate_to(4));
+    assert_eq!(4, combiner.len());
+
+    assert_eq!(Some(128), combiner.aggregate_to(3));
+    assert_eq!("[10.0.0.0/23, 10.0.2.0/24, 192.168.0.0/24]", combiner.to_string());
+
+    assert_eq!(Some(256), combiner.aggregate_to(2));
+    assert_eq!("[10.0.0.0/22, 192.168.0.0/24]", combiner.to_string());
+
+    assert_eq!(None, combiner.aggregate_to(0));
+    assert_eq!(2, combiner.len());
+
+    assert_eq!(Some(2u64.pow(32) - 1024 - 256), combiner.aggregate_to(1));
+    assert_eq!("[0.0.0.0/0]", combiner.to_string());
+
+    assert_eq!(Some(0), Ipv4CidrCombiner::new().aggregate_to(0));
+}
+
+#[test]
+fn aggregate_to_stops_at_max_entries() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    for host in
+        ["10.0.0.0", "10.0.0.2", "10.0.0.4", "10.0.0.6", "10.0.0.8", "10.0.0.9", "10.0.0.10"]
+    {
+        combiner.push(Ipv4Cidr::from_str(host).unwrap());
+    }
+
+    assert_eq!(6, combiner.len());
+
+    assert_eq!(Some(1), combiner.aggregate_to(5));
+    assert_eq!(
+        "[10.0.0.0/32, 10.0.0.2/32, 10.0.0.4/32, 10.0.0.6/32, 10.0.0.8/30]",
+        combiner.to_string()
+    );
+
+    // merging 10.0.0.4/30 would make it a sibling of 10.0.0.0/30, so 10.0.0.0/29 is taken at once
+    assert_eq!(Some(4), combiner.aggregate_to(3));
+    assert_eq!("[10.0.0.0/29, 10.0.0.8/30]", combiner.to_string());
+}
+
+#[test]
+fn aggregate_to_many_entries() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    let mut hosts = Vec::new();
+    let mut x = 1u32;
+
+    for _ in 0..4000 {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+
+        let host = Ipv4Cidr::new(Ipv4Addr::from(0x0A00_0000 | (x & 0x00FF_FFFF)), 32).unwrap();
+
+        hosts.push(host);
+        combiner.push(host);
+    }
+
+    let size = combiner.size();
+
+    let extra = combiner.aggregate_to(60).unwrap();
+
+    assert!(combiner.len() <= 60);
+    assert_eq!(size + extra, combiner.size());
+    assert!(hosts.iter().all(|host| combiner.contains(&host.first_address())));
+}
+
+#[test]
+fn policy() {
+    let mut combiner = Ipv4CidrCombiner::with_policy(MergePolicy {
+        min_network_length: 16,
+        max_network_length: 24,
+        ..MergePolicy::new()
+    });
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.0.128/25").unwrap());
+
+    assert_eq!("[10.0.0.0/24]", combiner.to_string());
+
+    combiner.push(Ipv4Cidr::from_str("10.0.1.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.2.0/23").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.4.0/22").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.8.0/21").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.16.0/20").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.32.0/19").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.64.0/18").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.128.0/17").unwrap());
+
+    assert_eq!("[10.0.0.0/16]", combiner.to_string());
+
+    combiner.push(Ipv4Cidr::from_str("10.1.0.0/16").unwrap());
+
+    assert_eq!("[10.0.0.0/16, 10.1.0.0/16]", combiner.to_string());
+
+    combiner.push(Ipv4Cidr::from_str("10.2.0.0/32").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.2.0.1/32").unwrap());
+
+    assert_eq!(4, combiner.len());
+
+    assert_eq!(None, combiner.aggregate_to(2));
+    assert_eq!(4, combiner.len());
+
+    // 10.2.0.0/31 is longer than the maximum network length, so its /24 is taken
+    assert_eq!(
+        "[10.0.0.0/16, 10.1.0.0/16, 10.2.0.0/24]",
+        combiner.covering_cidrs(3).unwrap().to_string()
+    );
+
+    assert_eq!(Some(254), combiner.aggregate_to(3));
+    assert_eq!("[10.0.0.0/16, 10.1.0.0/16, 10.2.0.0/24]", combiner.to_string());
+
+    let mut combiner = Ipv4CidrCombiner::with_policy(MergePolicy {
+        merge_siblings: false,
+        ..MergePolicy::new()
+    });
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.0.128/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.0.0.1").unwrap());
+
+    assert_eq!("[10.0.0.0/25, 10.0.0.128/25]", combiner.to_string());
+
+    assert_eq!(None, combiner.aggregate_to(1));
+    assert!(combiner.covering_cidrs(1).is_none());
+    assert_eq!(2, combiner.len());
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/24").unwrap());
+
+    assert_eq!("[10.0.0.0/24]", combiner.to_string());
+}
+
+#[test]
+fn subtract() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.3.0/24").unwrap());
+
+    let removed = combiner.subtract(&Ipv4Cidr::from_str("10.1.2.0/23").unwrap());
+
+    assert_eq!("[10.1.2.0/23]", removed.to_string());
+    assert_eq!(
+        "[10.0.0.0/16, 10.1.0.0/23, 10.1.4.0/22, 10.1.8.0/21, 10.1.16.0/20, 10.1.32.0/19, \
+         10.1.64.0/18, 10.1.128.0/17, 10.2.0.0/15, 10.4.0.0/14, 10.8.0.0/13, 10.16.0.0/12, \
+         10.32.0.0/11, 10.64.0.0/10, 10.128.0.0/9, 192.168.1.0/24, 192.168.3.0/24]",
+        combiner.to_string()
+    );
+
+    let removed = combiner.subtract(&Ipv4Cidr::from_str("192.168.0.0/16").unwrap());
+
+    assert_eq!("[192.168.1.0/24, 192.168.3.0/24]", removed.to_string());
+    assert_eq!(15, combiner.len());
+
+    assert!(combiner.subtract(&Ipv4Cidr::from_str("11.0.0.0/8").unwrap()).is_empty());
+
+    combiner.push(Ipv4Cidr::from_str("10.1.2.0/23").unwrap());
+
+    assert_eq!("[10.0.0.0/8]", combiner.to_string());
+
+    let removed = combiner.subtract(&Ipv4Cidr::from_str("0.0.0.0/0").unwrap());
+
+    assert_eq!("[10.0.0.0/8]", removed.to_string());
+    assert!(combiner.is_empty());
+}
+
+#[cfg(feature = "registry")]
+#[test]
+fn retain_global() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("8.8.8.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.20.0.0/16").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.0.0.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("198.51.100.7").unwrap());
+
+    let removed = combiner.retain_global();
+
+    assert_eq!(
+        "[10.20.0.0/16, 192.0.0.0/29, 192.0.0.8/32, 192.0.0.11/32, 192.0.0.12/30, 192.0.0.16/28, \
+         192.0.0.32/27, 192.0.0.64/26, 192.0.0.128/25, 198.51.100.7/32]",
+        removed.to_string()
+    );
+    assert_eq!("[8.8.8.0/24, 192.0.0.9/32, 192.0.0.10/32]", combiner.to_string());
+}
+
+#[test]
+fn free_blocks() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.4.0.0/26").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.4.0.128/25").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.4.2.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.5.0.0/16").unwrap());
+
+    let within = Ipv4Cidr::from_str("10.4.0.0/22").unwrap();
+
+    assert_eq!(
+        "[10.4.0.64/26, 10.4.1.0/24, 10.4.3.0/24]",
+        combiner.free_blocks(&within).to_string()
+    );
+
+    assert_eq!(Some(Ipv4Cidr::from_str("10.4.0.64/26").unwrap()), combiner.first_free(&within, 26));
+    assert_eq!(Some(Ipv4Cidr::from_str("10.4.1.0/25").unwrap()), combiner.first_free(&within, 25));
+    assert_eq!(Some(Ipv4Cidr::from_str("10.4.1.0/24").unwrap()), combiner.first_free(&within, 24));
+    assert_eq!(None, combiner.first_free(&within, 23));
+    assert_eq!(None, combiner.first_free(&within, 21));
+    assert_eq!(None, combiner.first_free(&within, 33));
+
+    let within = Ipv4Cidr::from_str("10.5.1.0/24").unwrap();
+
+    assert!(combiner.free_blocks(&within).is_empty());
+    assert_eq!(None, combiner.first_free(&within, 32));
+
+    let within = Ipv4Cidr::from_str("192.168.0.0/16").unwrap();
+
+    assert_eq!("[192.168.0.0/16]", combiner.free_blocks(&within).to_string());
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.168.0.0/26").unwrap()),
+        combiner.first_free(&within, 26)
+    );
+}
+
+#[test]
+fn covering_cidrs() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    assert_eq!(None, combiner.smallest_covering_cidr());
+    assert!(combiner.covering_cidrs(1).unwrap().is_empty());
+
+    for host in ["10.0.0.5", "10.0.0.9", "10.0.1.200", "10.0.3.1"] {
+        combiner.push(Ipv4Cidr::from_str(host).unwrap());
+    }
+
+    assert_eq!(Some(Ipv4Cidr::from_str("10.0.0.0/22").unwrap()), combiner.smallest_covering_cidr());
+
+    assert_eq!(
+        "[10.0.0.0/28, 10.0.1.200/32, 10.0.3.1/32]",
+        combiner.covering_cidrs(3).unwrap().to_string()
+    );
+    assert_eq!("[10.0.0.0/22]", combiner.covering_cidrs(1).unwrap().to_string());
+    assert!(combiner.covering_cidrs(0).is_none());
+
+    // the combiner itself is unchanged
+    assert_eq!(4, combiner.len());
+
+    combiner.push(Ipv4Cidr::from_str("0.0.0.0/1").unwrap());
+    combiner.push(Ipv4Cidr::from_str("255.255.255.255").unwrap());
+
+    assert_eq!(Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()), combiner.smallest_covering_cidr());
+}
+
+#[test]
+fn covering_cidrs_minimum_waste() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    for host in
+        ["10.0.0.0", "10.0.0.2", "10.0.0.4", "10.0.0.6", "10.0.0.8", "10.0.0.9", "10.0.0.10"]
+    {
+        combiner.push(Ipv4Cidr::from_str(host).unwrap());
+    }
+
+    let covering = combiner.covering_cidrs(3).unwrap();
+
+    assert_eq!("[10.0.0.0/29, 10.0.0.8/31, 10.0.0.10/32]", covering.to_string());
+    assert_eq!(combiner.size() + 4, covering.size());
+}
+
+/// Get the fewest extra addresses to cover the hosts (a bitmap of the addresses of a /28) under a prefix with at most `max_entries` prefixes.
+fn min_waste(hosts: u16, first: u32, bits: u32, max_entries: usize, max_length: u32) -> u64 {
+    let size = 1u32 << (32 - bits);
+    let mask = (((1u32 << size) - 1) << first) as u16;
+    let count = (hosts & mask).count_ones() as u64;
+
+    if count == 0 {
+        return 0;
+    }
+
+    let mut best = u64::MAX;
+
+    // a block longer than the maximum network length can only be a pushed host
+    if max_entries >= 1 && (bits <= max_length || bits == 32) {
+        best = size as u64 - count;
+    }
+
+    if bits < 32 {
+        let half = size / 2;
+
+        for a in 0..=max_entries {
+            let left = min_waste(hosts, first, bits + 1, a, max_length);
+            let right = min_waste(hosts, first + half, bits + 1, max_entries - a, max_length);
+
+            best = best.min(left.saturating_add(right));
+        }
+    }
+
+    best
+}
+
+#[test]
+fn covering_cidrs_brute_force() {
+    for hosts in (1u32..1 << 16).step_by(7) {
+        let hosts = hosts as u16;
+
+        for max_length in [29, 30, 32] {
+            let mut combiner = Ipv4CidrCombiner::with_policy(MergePolicy {
+                max_network_length: max_length as u8,
+                ..MergePolicy::new()
+            });
+
+            for i in 0..16 {
+                if hosts & (1 << i) != 0 {
+                    combiner.push(Ipv4Cidr::new(Ipv4Addr::new(10, 0, 0, i), 32).unwrap());
+                }
+            }
+
+            for max_entries in 1..=3 {
+                let covering = combiner.covering_cidrs(max_entries).unwrap();
+
+                assert!(covering.len() <= max_entries);
+                assert_eq!(
+                    min_waste(hosts, 0, 28, max_entries, max_length),
+                    covering.size() - combiner.size(),
+                    "{combiner} {max_entries} /{max_length}"
+                );
+                assert!(combiner.iter().all(|cidr| covering.contains(&cidr.first_address())));
+                assert!(covering.iter().all(|cidr| cidr.network_length() <= max_length as u8
+                    || combiner.contains(&cidr.first_address())));
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "iterator")]
+
+use core::str::FromStr;
+use std::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+use cidr_utils::iterator::{Ipv4CidrIpv4AddrIterator, Ipv4CidrIterator, Ipv4CidrU8ArrayIterator};
+
+#[test]
+fn step_by() {
+    let cidr = Ipv4Cidr::from_str("192.168.0.0/24").unwrap();
+
+    let addrs: Vec<Ipv4Addr> = Ipv4CidrIpv4AddrIterator::new(&cidr).step_by_u64(64).collect();
+
+    assert_eq!(
+        vec![
+            Ipv4Addr::new(192, 168, 0, 0),
+            Ipv4Addr::new(192, 168, 0, 64),
+            Ipv4Addr::new(192, 168, 0, 128),
+            Ipv4Addr::new(192, 168, 0, 192),
+        ],
+        addrs
+    );
+
+    let iter = Ipv4CidrU8ArrayIterator::new(&cidr).step_by_u64(100);
+
+    assert_eq!((3, Some(3)), iter.size_hint());
+    assert_eq!(3, iter.count());
+
+    let mut iter = Ipv4CidrU8ArrayIterator::new(&cidr);
+
+    iter.next();
+
+    let mut iter = iter.step_by_u64(100);
+
+    assert_eq!((3, Some(3)), iter.size_hint());
+    assert_eq!(Some([192, 168, 0, 1]), iter.next());
+    assert_eq!((2, Some(2)), iter.size_hint());
+    assert_eq!(Some([192, 168, 0, 101]), iter.next());
+    assert_eq!(Some([192, 168, 0, 201]), iter.next());
+    assert_eq!(None, iter.next());
+
+    let whole = Ipv4Cidr::from_str("0.0.0.0/0").unwrap();
+
+    let mut iter = Ipv4CidrIterator::new(&whole).step_by_u64(1 << 24);
+
+    assert_eq!(Some(0x0A00_0000), iter.nth(10));
+    assert_eq!(Some(0xFF00_0000), iter.nth(244));
+    assert_eq!(None, iter.next());
+
+    assert_eq!(None, Ipv4CidrIterator::new(&whole).step_by_u64(u64::MAX).nth(1));
+    assert_eq!(None, Ipv4CidrIterator::new(&whole).step_by_u64(2).nth(usize::MAX));
+}
+
+#[test]
+fn step_by_usize() {
+    let cidr = Ipv4Cidr::from_str("192.168.0.0/23").unwrap();
+
+    // `Iterator::step_by` is not shadowed, and gives the same sequence
+    for step in [1usize, 2, 3, 7, 64, 511, 512, 513] {
+        assert_eq!(
+            Ipv4CidrIpv4AddrIterator::new(&cidr).step_by(step).collect::<Vec<_>>(),
+            Ipv4CidrIpv4AddrIterator::new(&cidr).step_by_u64(step as u64).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Ipv4CidrU8ArrayIterator::new(&cidr).step_by(step).collect::<Vec<_>>(),
+            Ipv4CidrU8ArrayIterator::new(&cidr).step_by_u64(step as u64).collect::<Vec<_>>()
+        );
+
+        let mut iter = Ipv4CidrIterator::new(&cidr);
+
+        iter.next();
+
+        assert_eq!(
+            iter.clone().step_by(step).collect::<Vec<_>>(),
+            iter.step_by_u64(step as u64).collect::<Vec<_>>()
+        );
+    }
+}
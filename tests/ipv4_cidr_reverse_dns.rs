@@ -0,0 +1,54 @@
+#![cfg(feature = "dns")]
+
+use core::str::FromStr;
+use std::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+use cidr_utils::{combiner::Ipv4CidrCombiner, dns::Ipv4CidrReverseDns};
+
+#[test]
+fn ptr_name() {
+    assert_eq!(
+        "4.2.0.192.in-addr.arpa",
+        Ipv4CidrReverseDns::ptr_name(&Ipv4Addr::new(192, 0, 2, 4))
+    );
+    assert_eq!("0.0.0.0.in-addr.arpa", Ipv4CidrReverseDns::ptr_name(&Ipv4Addr::UNSPECIFIED));
+}
+
+#[test]
+fn zones() {
+    for (cidr, zones) in [
+        ("0.0.0.0/0", vec!["in-addr.arpa"]),
+        ("10.0.0.0/8", vec!["10.in-addr.arpa"]),
+        ("192.168.0.0/16", vec!["168.192.in-addr.arpa"]),
+        ("192.0.2.0/24", vec!["2.0.192.in-addr.arpa"]),
+        ("192.0.2.4/32", vec!["4.2.0.192.in-addr.arpa"]),
+        ("192.168.0.0/23", vec!["0.168.192.in-addr.arpa", "1.168.192.in-addr.arpa"]),
+        ("172.16.0.0/14", vec![
+            "16.172.in-addr.arpa",
+            "17.172.in-addr.arpa",
+            "18.172.in-addr.arpa",
+            "19.172.in-addr.arpa",
+        ]),
+        ("192.0.2.0/25", vec!["0/25.2.0.192.in-addr.arpa"]),
+        ("192.0.2.64/26", vec!["64/26.2.0.192.in-addr.arpa"]),
+        ("192.0.2.254/31", vec!["254/31.2.0.192.in-addr.arpa"]),
+    ] {
+        assert_eq!(zones, Ipv4CidrReverseDns::zones(&Ipv4Cidr::from_str(cidr).unwrap()), "{cidr}");
+    }
+
+    assert_eq!(128, Ipv4CidrReverseDns::zones(&Ipv4Cidr::from_str("10.0.0.0/9").unwrap()).len());
+}
+
+#[test]
+fn combiner_zones() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("192.0.2.128/26").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.0.0/23").unwrap());
+
+    assert_eq!(
+        vec!["128/26.2.0.192.in-addr.arpa", "0.168.192.in-addr.arpa", "1.168.192.in-addr.arpa"],
+        Ipv4CidrReverseDns::combiner_zones(&combiner)
+    );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "table")]
+
+use core::str::FromStr;
+use std::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+use cidr_utils::{combiner::Ipv4CidrCombiner, table::Ipv4CidrTable};
+
+#[test]
+fn lookup() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.3.7").unwrap());
+    combiner.push(Ipv4Cidr::from_str("255.255.255.255").unwrap());
+
+    let bytes = Ipv4CidrTable::encode(&combiner);
+
+    let table = Ipv4CidrTable::from_bytes(&bytes).unwrap();
+
+    assert_eq!(4, table.len());
+
+    for (index, cidr) in combiner.iter().enumerate() {
+        assert_eq!(Some(*cidr), table.get(index));
+    }
+
+    assert_eq!(None, table.get(4));
+
+    assert!(table.contains(&Ipv4Addr::new(10, 1, 2, 3)));
+    assert!(table.contains(&Ipv4Addr::new(192, 168, 3, 7)));
+    assert!(table.contains(&Ipv4Addr::new(255, 255, 255, 255)));
+    assert!(!table.contains(&Ipv4Addr::new(9, 255, 255, 255)));
+    assert!(!table.contains(&Ipv4Addr::new(192, 168, 2, 0)));
+    assert!(!table.contains(&Ipv4Addr::new(192, 168, 3, 8)));
+
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.168.1.0/24").unwrap()),
+        table.longest_match(&Ipv4Addr::new(192, 168, 1, 200))
+    );
+    assert_eq!(None, table.longest_match(&Ipv4Addr::new(0, 0, 0, 0)));
+}
+
+#[test]
+fn from_bytes() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    assert!(Ipv4CidrTable::from_bytes(&Ipv4CidrTable::encode(&combiner)).unwrap().is_empty());
+
+    combiner.push(Ipv4Cidr::from_str("0.0.0.0/0").unwrap());
+
+    let bytes = Ipv4CidrTable::encode(&combiner);
+
+    let table = Ipv4CidrTable::from_bytes(&bytes).unwrap();
+
+    assert_eq!(Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()), table.get(0));
+
+    assert!(Ipv4CidrTable::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+    assert!(Ipv4CidrTable::from_bytes(&bytes[1..]).is_none());
+    assert!(Ipv4CidrTable::from_bytes(b"").is_none());
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "registry")]
+
+use core::str::FromStr;
+use std::net::Ipv4Addr;
+
+use cidr::Ipv4Cidr;
+use cidr_utils::registry::{Ipv4SpecialPurposeRegistry, SpecialPurposeKind};
+
+#[test]
+fn classify_addr() {
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(192, 168, 1, 1)).unwrap();
+
+    assert!(entry.purpose().is_private());
+    assert!(!entry.purpose().globally_reachable);
+
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(192, 0, 0, 9)).unwrap();
+
+    assert_eq!("Port Control Protocol Anycast", entry.purpose().name);
+    assert!(entry.purpose().globally_reachable);
+
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(192, 0, 0, 100)).unwrap();
+
+    assert_eq!(SpecialPurposeKind::Other, entry.purpose().kind);
+    assert_eq!(Ipv4Cidr::from_str("192.0.0.0/24").unwrap(), entry.cidr());
+
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::BROADCAST).unwrap();
+
+    assert_eq!(SpecialPurposeKind::Broadcast, entry.purpose().kind);
+
+    assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(239, 1, 1, 1))
+        .unwrap()
+        .purpose()
+        .is_multicast());
+    assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(127, 0, 0, 1))
+        .unwrap()
+        .purpose()
+        .is_loopback());
+    assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(198, 19, 0, 1))
+        .unwrap()
+        .purpose()
+        .is_benchmarking());
+    assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(203, 0, 113, 5))
+        .unwrap()
+        .purpose()
+        .is_documentation());
+
+    assert!(Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(1, 1, 1, 1)).is_none());
+}
+
+#[test]
+fn relay_anycast() {
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(192, 88, 99, 1)).unwrap();
+
+    assert_eq!(Ipv4Cidr::from_str("192.88.99.0/24").unwrap(), entry.cidr());
+    assert_eq!("Deprecated (6to4 Relay Anycast)", entry.purpose().name);
+    assert_eq!("RFC 7526", entry.purpose().rfc);
+    assert!(!entry.purpose().source);
+    assert!(!entry.purpose().destination);
+    assert!(!entry.purpose().forwardable);
+    assert!(!entry.purpose().globally_reachable);
+    assert!(!entry.purpose().reserved_by_protocol);
+
+    let entry = Ipv4SpecialPurposeRegistry::classify_addr(&Ipv4Addr::new(192, 88, 99, 2)).unwrap();
+
+    assert_eq!(Ipv4Cidr::from_str("192.88.99.2/32").unwrap(), entry.cidr());
+    assert_eq!("6a44-relay anycast address", entry.purpose().name);
+    assert_eq!("RFC 6751", entry.purpose().rfc);
+    assert!(entry.purpose().source);
+    assert!(entry.purpose().destination);
+    assert!(entry.purpose().forwardable);
+    assert!(!entry.purpose().globally_reachable);
+    assert!(!entry.purpose().reserved_by_protocol);
+
+    let entry =
+        Ipv4SpecialPurposeRegistry::classify_cidr(&Ipv4Cidr::from_str("192.88.99.0/25").unwrap())
+            .unwrap();
+
+    assert_eq!(Ipv4Cidr::from_str("192.88.99.0/24").unwrap(), entry.cidr());
+}
+
+#[test]
+fn classify_cidr() {
+    let entry =
+        Ipv4SpecialPurposeRegistry::classify_cidr(&Ipv4Cidr::from_str("10.1.0.0/16").unwrap())
+            .unwrap();
+
+    assert_eq!(Ipv4Cidr::from_str("10.0.0.0/8").unwrap(), entry.cidr());
+
+    assert!(Ipv4SpecialPurposeRegistry::classify_cidr(&Ipv4Cidr::from_str("10.0.0.0/7").unwrap())
+        .is_none());
+}
+
+#[test]
+fn bogons() {
+    let bogons = Ipv4SpecialPurposeRegistry::bogons();
+
+    assert_eq!(
+        "[0.0.0.0/8, 10.0.0.0/8, 100.64.0.0/10, 127.0.0.0/8, 169.254.0.0/16, 172.16.0.0/12, \
+         192.0.0.0/24, 192.0.2.0/24, 192.88.99.0/24, 192.168.0.0/16, 198.18.0.0/15, \
+         198.51.100.0/24, 203.0.113.0/24, 224.0.0.0/3]",
+        bogons.to_string()
+    );
+
+    for entry in Ipv4SpecialPurposeRegistry::entries() {
+        assert!(
+            entry.purpose().globally_reachable || bogons.contains(&entry.cidr().first_address())
+        );
+    }
+}
@@ -1,9 +1,9 @@
 #![cfg(feature = "combiner")]
 
-use core::str::FromStr;
+use core::{net::Ipv6Addr, str::FromStr};
 
 use cidr::Ipv6Cidr;
-use cidr_utils::combiner::Ipv6CidrCombiner;
+use cidr_utils::combiner::{Ipv6CidrCombiner, MergePolicy};
 
 #[test]
 fn simple_test() {
@@ -29,3 +29,194 @@ fn should_combine_same_ip() {
     assert_eq!(1, combiner.len());
     assert_eq!("::ffff:192.168.1.100", combiner[0].to_string());
 }
+
+#[test]
+fn aggregate_to() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/48").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:3::/48").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:4::/48").unwrap());
+
+    assert_eq!(Some(2 << 80), combiner.aggregate_to(2));
+    assert_eq!("[2001:db8::/46, 2001:db8:4::/48]", combiner.to_string());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("::/128").unwrap());
+    combiner.push(Ipv6Cidr::from_str("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128").unwrap());
+
+    assert_eq!(Some(u128::MAX - 1), combiner.aggregate_to(1));
+    assert_eq!("[::/0]", combiner.to_string());
+}
+
+#[test]
+fn policy() {
+    let mut combiner = Ipv6CidrCombiner::with_policy(MergePolicy {
+        min_network_length: 32,
+        max_network_length: 48,
+        ..MergePolicy::new()
+    });
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/49").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:0:8000::/49").unwrap());
+
+    assert_eq!("[2001:db8::/48]", combiner.to_string());
+
+    // the siblings 2001:db8:1::/48, 2001:db8:2::/47, ..., 2001:db8:8000::/33
+    for bits in (33..=48).rev() {
+        let address = Ipv6Addr::from_str("2001:db8::").unwrap().to_bits() | (1 << (128 - bits));
+
+        combiner.push(Ipv6Cidr::new(Ipv6Addr::from_bits(address), bits).unwrap());
+    }
+
+    assert_eq!("[2001:db8::/32]", combiner.to_string());
+
+    combiner.push(Ipv6Cidr::from_str("2001:db9::/32").unwrap());
+
+    assert_eq!("[2001:db8::/32, 2001:db9::/32]", combiner.to_string());
+
+    combiner.push(Ipv6Cidr::from_str("2001:dba::/128").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:dba::1/128").unwrap());
+
+    assert_eq!(4, combiner.len());
+
+    assert_eq!(None, combiner.aggregate_to(2));
+    assert_eq!(4, combiner.len());
+
+    // 2001:dba::/127 is longer than the maximum network length, so its /48 is taken
+    assert_eq!(
+        "[2001:db8::/32, 2001:db9::/32, 2001:dba::/48]",
+        combiner.covering_cidrs(3).unwrap().to_string()
+    );
+
+    assert_eq!(Some((1 << 80) - 2), combiner.aggregate_to(3));
+    assert_eq!("[2001:db8::/32, 2001:db9::/32, 2001:dba::/48]", combiner.to_string());
+
+    let mut combiner = Ipv6CidrCombiner::with_policy(MergePolicy {
+        merge_siblings: false,
+        ..MergePolicy::new()
+    });
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/33").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:8000::/33").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8::1").unwrap());
+
+    assert_eq!("[2001:db8::/33, 2001:db8:8000::/33]", combiner.to_string());
+
+    assert_eq!(None, combiner.aggregate_to(1));
+    assert!(combiner.covering_cidrs(1).is_none());
+    assert_eq!(2, combiner.len());
+    assert_eq!(
+        MergePolicy {
+            merge_siblings: false,
+            ..MergePolicy::new()
+        },
+        combiner.policy()
+    );
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+
+    assert_eq!("[2001:db8::/32]", combiner.to_string());
+}
+
+#[cfg(feature = "registry")]
+#[test]
+fn retain_global() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2606:4700::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fd00::/8").unwrap());
+    combiner.push(Ipv6Cidr::from_str("::1").unwrap());
+
+    let removed = combiner.retain_global();
+
+    assert_eq!("[::1/128, 2001:db8::/32, fd00::/8]", removed.to_string());
+    assert_eq!("[2606:4700::/32]", combiner.to_string());
+}
+
+#[test]
+fn size_u128_saturating() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    assert_eq!(0, combiner.size_u128_saturating());
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/127").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fd00::/120").unwrap());
+
+    assert_eq!(258, combiner.size_u128_saturating());
+
+    combiner.push(Ipv6Cidr::from_str("::/1").unwrap());
+    combiner.push(Ipv6Cidr::from_str("8000::/2").unwrap());
+
+    assert_eq!((3 << 126) + 256, combiner.size_u128_saturating());
+
+    combiner.push(Ipv6Cidr::from_str("::/0").unwrap());
+
+    assert_eq!(u128::MAX, combiner.size_u128_saturating());
+}
+
+#[test]
+fn free_blocks() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/64").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:0:2::/63").unwrap());
+
+    let within = Ipv6Cidr::from_str("2001:db8::/62").unwrap();
+
+    assert_eq!("[2001:db8:0:1::/64]", combiner.free_blocks(&within).to_string());
+    assert_eq!(
+        Some(Ipv6Cidr::from_str("2001:db8:0:1::/120").unwrap()),
+        combiner.first_free(&within, 120)
+    );
+    assert_eq!(None, combiner.first_free(&within, 63));
+}
+
+#[test]
+fn covering_cidrs() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::1").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:0:1::1").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:ff::/48").unwrap());
+
+    assert_eq!(
+        Some(Ipv6Cidr::from_str("2001:db8::/40").unwrap()),
+        combiner.smallest_covering_cidr()
+    );
+    assert_eq!(
+        "[2001:db8::/63, 2001:db8:ff::/48]",
+        combiner.covering_cidrs(2).unwrap().to_string()
+    );
+}
+
+#[test]
+fn covering_cidrs_minimum_waste() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    for host in [
+        "2001:db8::",
+        "2001:db8::2",
+        "2001:db8::4",
+        "2001:db8::6",
+        "2001:db8::8",
+        "2001:db8::9",
+        "2001:db8::a",
+    ] {
+        combiner.push(Ipv6Cidr::from_str(host).unwrap());
+    }
+
+    let covering = combiner.covering_cidrs(3).unwrap();
+
+    assert_eq!("[2001:db8::/125, 2001:db8::8/127, 2001:db8::a/128]", covering.to_string());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("::/128").unwrap());
+    combiner.push(Ipv6Cidr::from_str("8000::/1").unwrap());
+
+    assert_eq!("[::/0]", combiner.covering_cidrs(1).unwrap().to_string());
+    assert_eq!(2, combiner.covering_cidrs(2).unwrap().len());
+}
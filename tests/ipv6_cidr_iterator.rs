@@ -0,0 +1,143 @@
+#![cfg(feature = "iterator")]
+
+use core::str::FromStr;
+use std::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+use cidr_utils::iterator::{Ipv6CidrIpv6AddrIterator, Ipv6CidrIterator, Ipv6CidrU16ArrayIterator};
+
+#[test]
+fn iterate() {
+    let cidr = Ipv6Cidr::from_str("2001:db8::/126").unwrap();
+
+    let addrs: Vec<Ipv6Addr> = Ipv6CidrIpv6AddrIterator::new(&cidr).collect();
+
+    assert_eq!(
+        vec![
+            Ipv6Addr::from_str("2001:db8::").unwrap(),
+            Ipv6Addr::from_str("2001:db8::1").unwrap(),
+            Ipv6Addr::from_str("2001:db8::2").unwrap(),
+            Ipv6Addr::from_str("2001:db8::3").unwrap(),
+        ],
+        addrs
+    );
+
+    let mut iter = Ipv6CidrIterator::new(&cidr);
+
+    assert_eq!(Some(cidr.first_address().to_bits()), iter.next());
+    assert_eq!(Some(cidr.last_address().to_bits()), iter.next_back());
+    assert_eq!(Some(cidr.first_address().to_bits() + 1), iter.next());
+    assert_eq!(Some(cidr.first_address().to_bits() + 2), iter.next_back());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn single_address() {
+    let cidr = Ipv6Cidr::from_str("::1").unwrap();
+
+    let mut iter = Ipv6CidrIterator::new(&cidr);
+
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(None, iter.next());
+
+    assert_eq!(Some(1), Ipv6CidrIterator::new(&cidr).last());
+    assert_eq!(Some(1), Ipv6CidrIterator::new(&cidr).next_back());
+}
+
+#[test]
+fn whole_space() {
+    let cidr = Ipv6Cidr::from_str("::/0").unwrap();
+
+    let mut iter = Ipv6CidrIterator::new(&cidr);
+
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(Some(u128::MAX), iter.next_back());
+    assert_eq!(Some(u128::MAX - 1), Ipv6CidrIterator::new(&cidr).nth_back_u128(1));
+    assert_eq!(Some(u128::MAX), Ipv6CidrIterator::new(&cidr).last());
+    assert_eq!(Some(u128::MAX), Ipv6CidrIterator::new(&cidr).nth_u128(u128::MAX));
+
+    assert_eq!(Some(1 << 127), iter.nth_u128((1 << 127) - 1));
+    assert_eq!(Some((1 << 127) + 1), iter.next());
+    assert_eq!(None, iter.nth_u128(u128::MAX));
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn nth() {
+    let cidr = Ipv6Cidr::from_str("2001:db8::/120").unwrap();
+
+    let mut iter = Ipv6CidrIpv6AddrIterator::new(&cidr);
+
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8::a").unwrap()), iter.nth(10));
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8::f5").unwrap()), iter.nth_back_u128(10));
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8::f4").unwrap()), iter.nth_back(0));
+    assert_eq!(None, iter.nth_u128(256));
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn step_by() {
+    let cidr = Ipv6Cidr::from_str("2001:db8:1::/48").unwrap();
+
+    // every /64 of a /48
+    let mut iter = Ipv6CidrIpv6AddrIterator::new(&cidr).step_by_u128(1 << 64);
+
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8:1::").unwrap()), iter.next());
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8:1:1::").unwrap()), iter.next());
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8:1:ff::").unwrap()), iter.nth(253));
+    assert_eq!(Some(Ipv6Addr::from_str("2001:db8:1:ffff::").unwrap()), iter.nth(65279));
+    assert_eq!(None, iter.next());
+
+    assert_eq!(65536, Ipv6CidrIterator::new(&cidr).step_by_u128(1 << 64).count());
+
+    let mut iter = Ipv6CidrIterator::new(&cidr);
+
+    iter.next();
+
+    let addrs: Vec<u128> = iter.step_by_u128(1 << 79).collect();
+
+    assert_eq!(
+        vec![cidr.first_address().to_bits() + 1, cidr.first_address().to_bits() + 1 + (1 << 79)],
+        addrs
+    );
+
+    let whole = Ipv6Cidr::from_str("::/0").unwrap();
+
+    let addrs: Vec<[u16; 8]> =
+        Ipv6CidrU16ArrayIterator::new(&whole).step_by_u128(u128::MAX).collect();
+
+    assert_eq!(vec![[0; 8], [0xFFFF; 8]], addrs);
+
+    assert_eq!(
+        Some(usize::MAX as u128 * 2),
+        Ipv6CidrIterator::new(&whole).step_by_u128(2).nth(usize::MAX)
+    );
+    assert_eq!(None, Ipv6CidrIterator::new(&cidr).step_by_u128(1 << 127).nth(1));
+}
+
+#[test]
+fn step_by_usize() {
+    let cidr = Ipv6Cidr::from_str("2001:db8::/119").unwrap();
+
+    // `Iterator::step_by` is not shadowed, and gives the same sequence
+    for step in [1usize, 2, 3, 7, 64, 511, 512, 513] {
+        assert_eq!(
+            Ipv6CidrIpv6AddrIterator::new(&cidr).step_by(step).collect::<Vec<_>>(),
+            Ipv6CidrIpv6AddrIterator::new(&cidr).step_by_u128(step as u128).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Ipv6CidrU16ArrayIterator::new(&cidr).step_by(step).collect::<Vec<_>>(),
+            Ipv6CidrU16ArrayIterator::new(&cidr).step_by_u128(step as u128).collect::<Vec<_>>()
+        );
+
+        let mut iter = Ipv6CidrIterator::new(&cidr);
+
+        iter.next();
+
+        assert_eq!(
+            iter.clone().step_by(step).collect::<Vec<_>>(),
+            iter.step_by_u128(step as u128).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "dns")]
+
+use core::str::FromStr;
+use std::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+use cidr_utils::{combiner::Ipv6CidrCombiner, dns::Ipv6CidrReverseDns};
+
+#[test]
+fn ptr_name() {
+    assert_eq!(
+        "b.a.9.8.7.6.5.0.4.0.0.0.3.0.0.0.2.0.0.0.1.0.0.0.0.0.0.0.1.2.3.4.ip6.arpa",
+        Ipv6CidrReverseDns::ptr_name(&Ipv6Addr::from_str("4321:0:1:2:3:4:567:89ab").unwrap())
+    );
+}
+
+#[test]
+fn zones() {
+    for (cidr, zones) in [
+        ("::/0", vec!["ip6.arpa"]),
+        ("2001:db8::/32", vec!["8.b.d.0.1.0.0.2.ip6.arpa"]),
+        ("2001:db8:ab00::/40", vec!["b.a.8.b.d.0.1.0.0.2.ip6.arpa"]),
+        ("2001:db8::/35", vec!["0.8.b.d.0.1.0.0.2.ip6.arpa", "1.8.b.d.0.1.0.0.2.ip6.arpa"]),
+        ("2001:db8::/34", vec![
+            "0.8.b.d.0.1.0.0.2.ip6.arpa",
+            "1.8.b.d.0.1.0.0.2.ip6.arpa",
+            "2.8.b.d.0.1.0.0.2.ip6.arpa",
+            "3.8.b.d.0.1.0.0.2.ip6.arpa",
+        ]),
+        ("::1/128", vec![
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.ip6.arpa",
+        ]),
+    ] {
+        assert_eq!(zones, Ipv6CidrReverseDns::zones(&Ipv6Cidr::from_str(cidr).unwrap()), "{cidr}");
+    }
+
+    assert_eq!(8, Ipv6CidrReverseDns::zones(&Ipv6Cidr::from_str("::/1").unwrap()).len());
+}
+
+#[test]
+fn combiner_zones() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8:8000::/34").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fd00::/8").unwrap());
+
+    assert_eq!(
+        vec![
+            "8.8.b.d.0.1.0.0.2.ip6.arpa",
+            "9.8.b.d.0.1.0.0.2.ip6.arpa",
+            "a.8.b.d.0.1.0.0.2.ip6.arpa",
+            "b.8.b.d.0.1.0.0.2.ip6.arpa",
+            "d.f.ip6.arpa",
+        ],
+        Ipv6CidrReverseDns::combiner_zones(&combiner)
+    );
+}
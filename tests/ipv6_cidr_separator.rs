@@ -3,7 +3,10 @@
 use std::str::FromStr;
 
 use cidr::Ipv6Cidr;
-use cidr_utils::{separator::Ipv6CidrSeparator, Ipv6CidrSize};
+#[cfg(feature = "bigint")]
+use cidr_utils::Ipv6CidrSize;
+use cidr_utils::{separator::Ipv6CidrSeparator, Ipv6CidrSizeU128};
+#[cfg(feature = "bigint")]
 use num_bigint::BigUint;
 
 #[test]
@@ -13,37 +16,66 @@ fn divide_by() {
     let result = Ipv6CidrSeparator::divide_by(&cidr, 4).unwrap();
 
     assert_eq!(4, result.len());
-    assert_eq!(BigUint::from(16384u128), result[0].size());
-    assert_eq!(BigUint::from(16384u128), result[1].size());
-    assert_eq!(BigUint::from(16384u128), result[2].size());
-    assert_eq!(BigUint::from(16384u128), result[3].size());
+    assert_eq!(16384, result[0].size_u128_saturating());
+    assert_eq!(16384, result[1].size_u128_saturating());
+    assert_eq!(16384, result[2].size_u128_saturating());
+    assert_eq!(16384, result[3].size_u128_saturating());
+
+    #[cfg(feature = "bigint")]
+    {
+        assert_eq!(BigUint::from(16384u128), result[0].size());
+        assert_eq!(BigUint::from(16384u128), result[1].size());
+        assert_eq!(BigUint::from(16384u128), result[2].size());
+        assert_eq!(BigUint::from(16384u128), result[3].size());
+    }
 
     let cidr = Ipv6Cidr::from_str("0:0:0:0:0:FFFF:FFFF:0/112").unwrap();
 
     let result = Ipv6CidrSeparator::divide_by(&cidr, 5).unwrap();
 
     assert_eq!(5, result.len());
-    assert_eq!(BigUint::from(13107u128), result[0].size());
-    assert_eq!(BigUint::from(13107u128), result[1].size());
-    assert_eq!(BigUint::from(13107u128), result[2].size());
-    assert_eq!(BigUint::from(13107u128), result[3].size());
-    assert_eq!(BigUint::from(13108u128), result[4].size());
+    assert_eq!(13107, result[0].size_u128_saturating());
+    assert_eq!(13107, result[1].size_u128_saturating());
+    assert_eq!(13107, result[2].size_u128_saturating());
+    assert_eq!(13107, result[3].size_u128_saturating());
+    assert_eq!(13108, result[4].size_u128_saturating());
+
+    #[cfg(feature = "bigint")]
+    {
+        assert_eq!(BigUint::from(13107u128), result[0].size());
+        assert_eq!(BigUint::from(13107u128), result[1].size());
+        assert_eq!(BigUint::from(13107u128), result[2].size());
+        assert_eq!(BigUint::from(13107u128), result[3].size());
+        assert_eq!(BigUint::from(13108u128), result[4].size());
+    }
 
     let cidr = Ipv6Cidr::from_str("::0/0").unwrap();
 
     let result = Ipv6CidrSeparator::divide_by(&cidr, 1).unwrap();
 
     assert_eq!(1, result.len());
-    assert_eq!(
-        BigUint::from_str("340282366920938463463374607431768211456").unwrap(),
-        result[0].size()
-    );
+    assert_eq!(u128::MAX, result[0].size_u128_saturating());
+    assert_eq!(None, result[0][0].size_u128_checked());
+
+    #[cfg(feature = "bigint")]
+    {
+        assert_eq!(
+            BigUint::from_str("340282366920938463463374607431768211456").unwrap(),
+            result[0].size()
+        );
+    }
 
     let result = Ipv6CidrSeparator::divide_by(&cidr, 2).unwrap();
 
     assert_eq!(2, result.len());
-    assert_eq!(BigUint::from(2u128.pow(127)), result[0].size());
-    assert_eq!(BigUint::from(2u128.pow(127)), result[1].size());
+    assert_eq!(2u128.pow(127), result[0].size_u128_saturating());
+    assert_eq!(2u128.pow(127), result[1].size_u128_saturating());
+
+    #[cfg(feature = "bigint")]
+    {
+        assert_eq!(BigUint::from(2u128.pow(127)), result[0].size());
+        assert_eq!(BigUint::from(2u128.pow(127)), result[1].size());
+    }
 }
 
 #[test]
@@ -53,8 +85,16 @@ fn sub_networks() {
     let result = Ipv6CidrSeparator::sub_networks(&cidr, 114).unwrap();
 
     assert_eq!(4, result.len());
-    assert_eq!(BigUint::from(16384u128), result[0].size());
-    assert_eq!(BigUint::from(16384u128), result[1].size());
-    assert_eq!(BigUint::from(16384u128), result[2].size());
-    assert_eq!(BigUint::from(16384u128), result[3].size());
+    assert_eq!(Some(16384), result[0].size_u128_checked());
+    assert_eq!(Some(16384), result[1].size_u128_checked());
+    assert_eq!(Some(16384), result[2].size_u128_checked());
+    assert_eq!(Some(16384), result[3].size_u128_checked());
+
+    #[cfg(feature = "bigint")]
+    {
+        assert_eq!(BigUint::from(16384u128), result[0].size());
+        assert_eq!(BigUint::from(16384u128), result[1].size());
+        assert_eq!(BigUint::from(16384u128), result[2].size());
+        assert_eq!(BigUint::from(16384u128), result[3].size());
+    }
 }
@@ -0,0 +1,47 @@
+#![cfg(feature = "table")]
+
+use core::str::FromStr;
+use std::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+use cidr_utils::{combiner::Ipv6CidrCombiner, table::Ipv6CidrTable};
+
+#[test]
+fn lookup() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+    combiner.push(Ipv6Cidr::from_str("fe80::/10").unwrap());
+    combiner.push(Ipv6Cidr::from_str("::1").unwrap());
+
+    let bytes = Ipv6CidrTable::encode(&combiner);
+
+    let table = Ipv6CidrTable::from_bytes(&bytes).unwrap();
+
+    assert_eq!(3, table.len());
+
+    for (index, cidr) in combiner.iter().enumerate() {
+        assert_eq!(Some(*cidr), table.get(index));
+    }
+
+    assert!(table.contains(&Ipv6Addr::LOCALHOST));
+    assert!(table.contains(&Ipv6Addr::from_str("2001:db8:ffff::1").unwrap()));
+    assert!(!table.contains(&Ipv6Addr::from_str("2001:db9::").unwrap()));
+    assert!(!table.contains(&Ipv6Addr::UNSPECIFIED));
+
+    assert_eq!(
+        Some(Ipv6Cidr::from_str("fe80::/10").unwrap()),
+        table.longest_match(&Ipv6Addr::from_str("fe80::1").unwrap())
+    );
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("::/0").unwrap());
+
+    let bytes = Ipv6CidrTable::encode(&combiner);
+
+    let table = Ipv6CidrTable::from_bytes(&bytes).unwrap();
+
+    assert_eq!(Some(Ipv6Cidr::from_str("::/0").unwrap()), table.get(0));
+    assert!(table.contains(&Ipv6Addr::from(u128::MAX)));
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "registry")]
+
+use core::str::FromStr;
+use std::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+use cidr_utils::registry::{Ipv6SpecialPurposeRegistry, SpecialPurposeKind};
+
+#[test]
+fn classify_addr() {
+    let entry = Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::LOCALHOST).unwrap();
+
+    assert!(entry.purpose().is_loopback());
+
+    let entry =
+        Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("2001::1").unwrap()).unwrap();
+
+    assert_eq!(SpecialPurposeKind::Tunneling, entry.purpose().kind);
+    assert_eq!(Ipv6Cidr::from_str("2001::/32").unwrap(), entry.cidr());
+
+    let entry =
+        Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("2001:1::1").unwrap())
+            .unwrap();
+
+    assert!(entry.purpose().globally_reachable);
+
+    assert!(Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("3fff::1").unwrap())
+        .unwrap()
+        .purpose()
+        .is_documentation());
+    assert!(Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("ff02::1").unwrap())
+        .unwrap()
+        .purpose()
+        .is_multicast());
+
+    assert!(
+        Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("2600::").unwrap()).is_none()
+    );
+}
+
+#[test]
+fn classify_cidr() {
+    let entry = Ipv6SpecialPurposeRegistry::classify_cidr(
+        &Ipv6Cidr::from_str("2001:db8:1234::/48").unwrap(),
+    )
+    .unwrap();
+
+    assert!(entry.purpose().is_documentation());
+
+    assert!(Ipv6SpecialPurposeRegistry::classify_cidr(&Ipv6Cidr::from_str("2000::/3").unwrap())
+        .is_none());
+}
+
+#[test]
+fn orchid_v1() {
+    let entry =
+        Ipv6SpecialPurposeRegistry::classify_addr(&Ipv6Addr::from_str("2001:10::1").unwrap())
+            .unwrap();
+
+    assert_eq!(Ipv6Cidr::from_str("2001:10::/28").unwrap(), entry.cidr());
+    assert_eq!("Deprecated (previously ORCHID)", entry.purpose().name);
+    assert_eq!("RFC 4843", entry.purpose().rfc);
+    assert!(!entry.purpose().globally_reachable);
+
+    let entry =
+        Ipv6SpecialPurposeRegistry::classify_cidr(&Ipv6Cidr::from_str("2001:1f::/32").unwrap())
+            .unwrap();
+
+    assert_eq!(Ipv6Cidr::from_str("2001:10::/28").unwrap(), entry.cidr());
+}
+
+#[test]
+fn bogons() {
+    let bogons = Ipv6SpecialPurposeRegistry::bogons();
+
+    assert_eq!(
+        "[::/127, ::ffff:0.0.0.0/96, 64:ff9b:1::/48, 100::/64, 2001::/23, 2001:db8::/32, \
+         2002::/16, 3fff::/20, 5f00::/16, fc00::/7, fe80::/10, ff00::/8]",
+        bogons.to_string()
+    );
+
+    for entry in Ipv6SpecialPurposeRegistry::entries() {
+        assert!(
+            entry.purpose().globally_reachable || bogons.contains(&entry.cidr().first_address())
+        );
+    }
+
+    // unlike `non_global`, the globally reachable blocks nested in the bogons are kept
+    let pcp_anycast = Ipv6Addr::from_str("2001:1::1").unwrap();
+
+    assert!(bogons.contains(&pcp_anycast));
+    assert!(!Ipv6SpecialPurposeRegistry::non_global().contains(&pcp_anycast));
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "rayon")]
+
+use std::{
+    net::{Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    iterator::*,
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[test]
+fn ipv4_par_iter() {
+    let cidr = Ipv4Cidr::from_str("10.0.0.0/12").unwrap();
+
+    let expected: Vec<Ipv4Addr> = Ipv4CidrIpv4AddrIterator::new(&cidr).collect();
+    let actual: Vec<Ipv4Addr> = Ipv4CidrIpv4AddrIterator::new(&cidr).into_par_iter().collect();
+
+    assert_eq!(expected, actual);
+
+    let sum: u64 = Ipv4CidrIterator::new(&cidr).into_par_iter().map(u64::from).sum();
+
+    assert_eq!(Ipv4CidrIterator::new(&cidr).map(u64::from).sum::<u64>(), sum);
+
+    let count = Ipv4CidrU8ArrayIterator::new(&cidr).into_par_iter().filter(|a| a[3] == 0).count();
+
+    assert_eq!(4096, count);
+}
+
+#[test]
+fn ipv4_par_iter_partially_consumed() {
+    let cidr = Ipv4Cidr::from_str("192.168.0.0/24").unwrap();
+
+    let mut iter = Ipv4CidrIpv4AddrIterator::new(&cidr);
+
+    iter.nth_u64(9);
+    iter.next_back();
+
+    let actual: Vec<Ipv4Addr> = iter.into_par_iter().collect();
+
+    assert_eq!(245, actual.len());
+    assert_eq!(Ipv4Addr::new(192, 168, 0, 10), actual[0]);
+    assert_eq!(Ipv4Addr::new(192, 168, 0, 254), actual[244]);
+
+    let single = Ipv4Cidr::from_str("192.168.0.1/32").unwrap();
+
+    assert_eq!(1, Ipv4CidrIterator::new(&single).into_par_iter().count());
+}
+
+#[test]
+fn ipv6_par_iter() {
+    let cidr = Ipv6Cidr::from_str("2001:db8::/108").unwrap();
+
+    let expected: Vec<Ipv6Addr> = Ipv6CidrIpv6AddrIterator::new(&cidr).collect();
+    let actual: Vec<Ipv6Addr> = Ipv6CidrIpv6AddrIterator::new(&cidr).into_par_iter().collect();
+
+    assert_eq!(expected, actual);
+
+    let expected: Vec<[u16; 8]> = Ipv6CidrU16ArrayIterator::new(&cidr).collect();
+    let actual: Vec<[u16; 8]> = Ipv6CidrU16ArrayIterator::new(&cidr).into_par_iter().collect();
+
+    assert_eq!(expected, actual);
+
+    let sum: u128 = Ipv6CidrIterator::new(&cidr).into_par_iter().map(|a| a & 0xFFFFF).sum();
+
+    assert_eq!((1 << 20) * ((1 << 20) - 1) / 2, sum);
+
+    let count = Ipv6CidrU8ArrayIterator::new(&cidr).into_par_iter().filter(|a| a[15] == 0).count();
+
+    assert_eq!(4096, count);
+}
+
+#[test]
+fn combiner_par_addrs() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/16").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.3.7/32").unwrap());
+
+    let addrs: Vec<Ipv4Addr> = combiner.par_addrs().collect();
+
+    assert_eq!(combiner.size(), addrs.len() as u64);
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 0), addrs[0]);
+    assert_eq!(Ipv4Addr::new(192, 168, 1, 0), addrs[65536]);
+    assert_eq!(Ipv4Addr::new(192, 168, 3, 7), addrs[65536 + 256]);
+    assert!(addrs.windows(2).all(|w| w[0] < w[1]));
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/112").unwrap());
+    combiner.push(Ipv6Cidr::from_str("2001:db8:1::/120").unwrap());
+
+    assert_eq!(65536 + 256, combiner.par_addrs().count());
+    assert!(combiner.par_addrs().all(|addr| combiner.contains(&addr)));
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "provenance")]
+
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, MergePolicy},
+    provenance::{Ipv4ProvenanceCombiner, Ipv6ProvenanceCombiner},
+};
+
+#[test]
+fn ipv4_provenance() {
+    let inputs = ["10.0.0.0/25", "10.0.0.128/25", "10.0.1.0/24", "192.168.0.0/24", "10.0.0.0/26"];
+
+    let combiner: Ipv4ProvenanceCombiner<usize> = inputs
+        .iter()
+        .enumerate()
+        .map(|(index, cidr)| (Ipv4Cidr::from_str(cidr).unwrap(), index))
+        .collect();
+
+    assert_eq!(2, combiner.len());
+    assert_eq!(
+        vec![
+            (Ipv4Cidr::from_str("10.0.0.0/23").unwrap(), vec![0, 1, 2, 4]),
+            (Ipv4Cidr::from_str("192.168.0.0/24").unwrap(), vec![3]),
+        ],
+        combiner.iter().map(|(cidr, tags)| (*cidr, tags.to_vec())).collect::<Vec<_>>()
+    );
+
+    assert_eq!(Some(&[3][..]), combiner.tags(1));
+    assert_eq!(None, combiner.tags(2));
+    assert!(combiner.contains(&Ipv4Addr::new(10, 0, 1, 255)));
+    assert_eq!(None, combiner.find(&Ipv4Addr::new(10, 0, 2, 0)));
+}
+
+#[test]
+fn ipv4_provenance_same_as_combiner() {
+    let mut state = 0x9E37_79B9u32;
+
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        state
+    };
+
+    let policy = MergePolicy {
+        min_network_length: 20,
+        ..MergePolicy::new()
+    };
+
+    let mut provenance_combiner = Ipv4ProvenanceCombiner::with_policy(policy);
+    let mut combiner = Ipv4CidrCombiner::with_policy(policy);
+
+    for i in 0..2000 {
+        let bits = 18 + (next() % 15) as u8;
+        let addr = 0x0A00_0000 | (next() & 0xFFFF) << 6;
+        let mask = u32::MAX << (32 - bits);
+
+        let cidr = Ipv4Cidr::new(Ipv4Addr::from(addr & mask), bits).unwrap();
+
+        provenance_combiner.push(cidr, i);
+        combiner.push(cidr);
+
+        assert_eq!(combiner.as_slice(), provenance_combiner.cidrs());
+    }
+
+    let mut tags: Vec<usize> =
+        provenance_combiner.iter().flat_map(|(_, tags)| tags.iter().copied()).collect();
+
+    tags.sort_unstable();
+
+    assert_eq!((0..2000).collect::<Vec<_>>(), tags);
+    assert_eq!(combiner.as_slice(), provenance_combiner.to_combiner().as_slice());
+}
+
+#[test]
+fn ipv6_provenance() {
+    let mut combiner = Ipv6ProvenanceCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("2001:db8::/33").unwrap(), "a");
+    combiner.push(Ipv6Cidr::from_str("2001:db8:8000::/33").unwrap(), "b");
+
+    let (cidr, tags) = combiner.find(&Ipv6Addr::from_str("2001:db8::1").unwrap()).unwrap();
+
+    assert_eq!(Ipv6Cidr::from_str("2001:db8::/32").unwrap(), *cidr);
+    assert_eq!(&["a", "b"], tags);
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "translation")]
+
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::{
+    combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner},
+    translation::{Ipv4Mapped, Ipv6Tunnel, Isatap, Nat64Prefix, SixToFour, Teredo},
+};
+
+#[test]
+fn nat64_rfc6052_examples() {
+    let ipv4 = Ipv4Addr::new(192, 0, 2, 33);
+
+    for (prefix, ipv6) in [
+        ("2001:db8::/32", "2001:db8:c000:221::"),
+        ("2001:db8:100::/40", "2001:db8:1c0:2:21::"),
+        ("2001:db8:122::/48", "2001:db8:122:c000:2:2100::"),
+        ("2001:db8:122:300::/56", "2001:db8:122:3c0:0:221::"),
+        ("2001:db8:122:344::/64", "2001:db8:122:344:c0:2:2100:0"),
+        ("2001:db8:122:344::/96", "2001:db8:122:344::192.0.2.33"),
+        ("64:ff9b::/96", "64:ff9b::192.0.2.33"),
+    ] {
+        let prefix = Nat64Prefix::new(Ipv6Cidr::from_str(prefix).unwrap()).unwrap();
+        let ipv6 = Ipv6Addr::from_str(ipv6).unwrap();
+
+        assert_eq!(ipv6, prefix.embed_addr(&ipv4));
+        assert_eq!(Some(ipv4), prefix.extract_addr(&ipv6));
+
+        for bits in 0..=32 {
+            let mask = u32::MAX.checked_shl(32 - bits as u32).unwrap_or(0);
+            let cidr = Ipv4Cidr::new(Ipv4Addr::from(ipv4.to_bits() & mask), bits).unwrap();
+
+            assert_eq!(Some(cidr), prefix.extract_cidr(&prefix.embed_cidr(&cidr)));
+        }
+    }
+
+    assert_eq!(
+        Nat64Prefix::well_known(),
+        Nat64Prefix::new(Ipv6Cidr::from_str("64:ff9b::/96").unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn nat64_invalid() {
+    assert!(Nat64Prefix::new(Ipv6Cidr::from_str("2001:db8::/33").unwrap()).is_none());
+    assert!(Nat64Prefix::new(Ipv6Cidr::from_str("2001:db8:0:0:100::/96").unwrap()).is_none());
+
+    let prefix = Nat64Prefix::new(Ipv6Cidr::from_str("2001:db8:122:344::/64").unwrap()).unwrap();
+
+    assert_eq!(None, prefix.extract_addr(&Ipv6Addr::from_str("2001:db8:122:344:1c0::").unwrap()));
+    assert_eq!(None, prefix.extract_addr(&Ipv6Addr::from_str("2001:db8:122:345::").unwrap()));
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()),
+        prefix.extract_cidr(&Ipv6Cidr::from_str("2001:db8::/32").unwrap())
+    );
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()),
+        prefix.extract_cidr(&Ipv6Cidr::from_str("2001:db8:122:344::/68").unwrap())
+    );
+    assert_eq!(
+        None,
+        prefix.extract_cidr(&Ipv6Cidr::from_str("2001:db8:122:344:1000::/68").unwrap())
+    );
+    assert_eq!(None, prefix.extract_cidr(&Ipv6Cidr::from_str("2001:db9::/32").unwrap()));
+}
+
+#[test]
+fn combiners() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+    combiner.push(Ipv4Cidr::from_str("192.168.1.1").unwrap());
+
+    let ipv6_combiner = Nat64Prefix::well_known().embed_combiner(&combiner);
+
+    assert_eq!("[64:ff9b::a00:0/104, 64:ff9b::c0a8:101/128]", ipv6_combiner.to_string());
+    assert_eq!(
+        combiner.to_string(),
+        Nat64Prefix::well_known().extract_combiner(&ipv6_combiner).to_string()
+    );
+
+    let ipv6_combiner = Ipv4Mapped::to_ipv6_combiner(&combiner);
+
+    assert_eq!("[::ffff:10.0.0.0/104, ::ffff:192.168.1.1/128]", ipv6_combiner.to_string());
+
+    let mut ipv6_combiner = ipv6_combiner;
+
+    ipv6_combiner.push(Ipv6Cidr::from_str("2001:db8::/32").unwrap());
+
+    assert_eq!(combiner.to_string(), Ipv4Mapped::from_ipv6_combiner(&ipv6_combiner).to_string());
+
+    let mut ipv6_combiner = Ipv6CidrCombiner::new();
+
+    ipv6_combiner.push(Ipv6Cidr::from_str("::/64").unwrap());
+
+    assert_eq!("[0.0.0.0/0]", Ipv4Mapped::from_ipv6_combiner(&ipv6_combiner).to_string());
+}
+
+#[test]
+fn six_to_four() {
+    assert_eq!(
+        Some(Ipv4Addr::new(192, 0, 2, 4)),
+        SixToFour::extract_addr(&Ipv6Addr::from_str("2002:c000:204::1").unwrap())
+    );
+    assert_eq!(None, SixToFour::extract_addr(&Ipv6Addr::from_str("2001:db8::1").unwrap()));
+
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.0.2.0/24").unwrap()),
+        SixToFour::extract_cidr(&Ipv6Cidr::from_str("2002:c000:200::/40").unwrap())
+    );
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.0.2.4/32").unwrap()),
+        SixToFour::extract_cidr(&Ipv6Cidr::from_str("2002:c000:204:1::/64").unwrap())
+    );
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()),
+        SixToFour::extract_cidr(&Ipv6Cidr::from_str("2000::/3").unwrap())
+    );
+    assert_eq!(None, SixToFour::extract_cidr(&Ipv6Cidr::from_str("2001:db8::/32").unwrap()));
+
+    assert_eq!(
+        Ipv6Cidr::from_str("2002:c000:204::/48").unwrap(),
+        SixToFour::to_ipv6_cidr(&Ipv4Cidr::from_str("192.0.2.4").unwrap())
+    );
+}
+
+#[test]
+fn teredo() {
+    let ipv6 = Ipv6Addr::from_str("2001:0:4136:e378:8000:63bf:3fff:fdd2").unwrap();
+    let teredo = Teredo::decode(&ipv6).unwrap();
+
+    assert_eq!(Ipv4Addr::new(65, 54, 227, 120), teredo.server);
+    assert_eq!(Ipv4Addr::new(192, 0, 2, 45), teredo.client);
+    assert_eq!(40000, teredo.port);
+    assert_eq!(0x8000, teredo.flags);
+
+    assert_eq!(None, Teredo::decode(&Ipv6Addr::from_str("2001:db8::1").unwrap()));
+
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("65.54.227.120/32").unwrap()),
+        Teredo::server_cidr(&Ipv6Cidr::from_str("2001:0:4136:e378::/64").unwrap())
+    );
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.0.2.0/24").unwrap()),
+        Teredo::client_cidr(
+            &Ipv6Cidr::from_str("2001:0:4136:e378:8000:63bf:3fff:fd00/120").unwrap()
+        )
+    );
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("0.0.0.0/0").unwrap()),
+        Teredo::client_cidr(&Ipv6Cidr::from_str("2001::/32").unwrap())
+    );
+    assert_eq!(None, Teredo::client_cidr(&Ipv6Cidr::from_str("2002::/16").unwrap()));
+}
+
+#[test]
+fn isatap() {
+    for ipv6 in ["fe80::5efe:c000:28f", "2001:db8::200:5efe:c000:28f"] {
+        assert_eq!(
+            Some(Ipv4Addr::new(192, 0, 2, 143)),
+            Isatap::extract_addr(&Ipv6Addr::from_str(ipv6).unwrap())
+        );
+    }
+
+    assert_eq!(None, Isatap::extract_addr(&Ipv6Addr::from_str("fe80::1").unwrap()));
+
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.0.2.0/24").unwrap()),
+        Isatap::extract_cidr(&Ipv6Cidr::from_str("fe80::5efe:c000:200/120").unwrap())
+    );
+    assert_eq!(None, Isatap::extract_cidr(&Ipv6Cidr::from_str("fe80::/64").unwrap()));
+}
+
+#[test]
+fn tunnel_detect() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("192.0.2.0/24").unwrap());
+
+    for (ipv6, expected) in [
+        ("2002:c000:204::1", Ipv6Tunnel::SixToFour),
+        ("2001:0:4136:e378:8000:63bf:3fff:fdd2", Ipv6Tunnel::Teredo),
+        ("fe80::5efe:c000:28f", Ipv6Tunnel::Isatap),
+    ] {
+        let (tunnel, ipv4) = Ipv6Tunnel::detect(&Ipv6Addr::from_str(ipv6).unwrap()).unwrap();
+
+        assert_eq!(expected, tunnel);
+        assert!(combiner.contains(&ipv4));
+    }
+
+    assert_eq!(None, Ipv6Tunnel::detect(&Ipv6Addr::from_str("2001:db8::1").unwrap()));
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "utilization")]
+
+use core::str::FromStr;
+
+use cidr::{Ipv4Cidr, Ipv6Cidr};
+use cidr_utils::combiner::{Ipv4CidrCombiner, Ipv6CidrCombiner};
+
+#[test]
+fn ipv4_utilization() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.4.0.0/24").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.4.1.0/26").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.4.1.128/26").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.4.2.0/26").unwrap());
+    combiner.push(Ipv4Cidr::from_str("10.5.0.0/16").unwrap());
+
+    let utilization = combiner.utilization(&Ipv4Cidr::from_str("10.4.0.0/22").unwrap());
+
+    assert_eq!(1024, utilization.total());
+    assert_eq!(448, utilization.used());
+    assert_eq!(576, utilization.free());
+    assert_eq!(43.75, utilization.percentage());
+    assert_eq!(4, utilization.entries());
+    assert_eq!(5, utilization.free_blocks());
+    assert_eq!(Some(Ipv4Cidr::from_str("10.4.3.0/24").unwrap()), utilization.largest_free_block());
+    assert_eq!(1.0 - 256.0 / 576.0, utilization.fragmentation());
+    assert_eq!(1, utilization.histogram()[24]);
+    assert_eq!(3, utilization.histogram()[26]);
+    assert_eq!(4, utilization.histogram().iter().sum::<usize>());
+}
+
+#[test]
+fn ipv4_utilization_bounds() {
+    let mut combiner = Ipv4CidrCombiner::new();
+
+    combiner.push(Ipv4Cidr::from_str("10.0.0.0/8").unwrap());
+
+    let utilization = combiner.utilization(&Ipv4Cidr::from_str("10.1.0.0/16").unwrap());
+
+    assert_eq!(100.0, utilization.percentage());
+    assert_eq!(None, utilization.largest_free_block());
+    assert_eq!(0.0, utilization.fragmentation());
+    assert_eq!(1, utilization.histogram()[16]);
+
+    let utilization = combiner.utilization(&Ipv4Cidr::from_str("192.168.0.0/16").unwrap());
+
+    assert_eq!(0.0, utilization.percentage());
+    assert_eq!(0, utilization.entries());
+    assert_eq!(
+        Some(Ipv4Cidr::from_str("192.168.0.0/16").unwrap()),
+        utilization.largest_free_block()
+    );
+    assert_eq!(0.0, utilization.fragmentation());
+}
+
+#[test]
+fn ipv6_utilization() {
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(Ipv6Cidr::from_str("::/1").unwrap());
+
+    let utilization = combiner.utilization(&Ipv6Cidr::from_str("::/0").unwrap());
+
+    assert_eq!(u128::MAX, utilization.total_u128_saturating());
+    assert_eq!(1 << 127, utilization.used_u128_saturating());
+    assert_eq!(1 << 127, utilization.free_u128_saturating());
+    assert_eq!(50.0, utilization.percentage());
+    assert_eq!(Some(Ipv6Cidr::from_str("8000::/1").unwrap()), utilization.largest_free_block());
+    assert_eq!(0.0, utilization.fragmentation());
+    assert_eq!(1, utilization.histogram()[1]);
+}
+
+#[test]
+fn ipv6_utilization_whole_space() {
+    let whole = Ipv6Cidr::from_str("::/0").unwrap();
+
+    let utilization = Ipv6CidrCombiner::new().utilization(&whole);
+
+    assert_eq!(0, utilization.used_u128_saturating());
+    assert_eq!(u128::MAX, utilization.free_u128_saturating());
+    assert_eq!(Some(whole), utilization.largest_free_block());
+
+    let mut combiner = Ipv6CidrCombiner::new();
+
+    combiner.push(whole);
+
+    let utilization = combiner.utilization(&whole);
+
+    assert_eq!(u128::MAX, utilization.used_u128_saturating());
+    assert_eq!(0, utilization.free_u128_saturating());
+    assert_eq!(0, utilization.free_blocks());
+    assert_eq!(None, utilization.largest_free_block());
+    assert_eq!(100.0, utilization.percentage());
+    assert_eq!(0.0, utilization.fragmentation());
+}